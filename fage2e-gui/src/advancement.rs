@@ -9,12 +9,14 @@ mod class;
 mod class_selection;
 mod health;
 mod initial_weapon_group;
+mod magic_points;
 
 pub use ability_determination::*;
 pub use ancestry::*;
 pub use class_selection::*;
 pub use health::*;
 pub use initial_weapon_group::*;
+pub use magic_points::*;
 
 #[component]
 pub fn SelectName(mut name: Signal<fage2e::SelectName>) -> Element {
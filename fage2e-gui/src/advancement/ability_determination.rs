@@ -1,9 +1,10 @@
-use std::ops::{Deref, DerefMut};
+use std::ops::Deref;
 
 use dioxus::prelude::*;
-use strum::IntoEnumIterator;
+use strum::{EnumIter, IntoEnumIterator};
 
 use fage2e;
+use fage2e::Advancement;
 
 #[component]
 pub fn ManuallyEnterAbilities(mut abilities: Signal<fage2e::ManuallyEnterAbilities>) -> Element {
@@ -58,8 +59,179 @@ pub fn ManuallyEnterAbilities(mut abilities: Signal<fage2e::ManuallyEnterAbiliti
     }
 }
 
+/// The methods for determining a character's initial abilities.
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq)]
+enum AbilityDeterminationMode {
+    Select,
+    Roll,
+    Manual,
+}
+
+impl std::fmt::Display for AbilityDeterminationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Select => write!(f, "Select"),
+            Self::Roll => write!(f, "Roll"),
+            Self::Manual => write!(f, "Manual"),
+        }
+    }
+}
+
+#[component]
+pub fn SelectAbilities(mut abilities: Signal<fage2e::SelectAbilities>) -> Element {
+    let scores = use_memo(move || {
+        let mut character = fage2e::Character::new();
+        let _ = abilities().apply_all(&mut character);
+        character.mechanical_properties.abilities
+    });
+    let remaining = use_memo(move || {
+        (*abilities.read()).advancements.iter().filter(|adv| adv.is_none()).count()
+    });
+
+    use crate::widget::Button;
+
+    rsx! {
+        p { class: "label", "Remaining advancements: {remaining()}" }
+        table {
+            for ability in fage2e::Ability::iter() {
+                tr {
+                    td {
+                        padding_right: "5px",
+                        "{ability}: "
+                    }
+                    td {
+                        padding_right: "5px",
+                        "{scores().get(ability).score} / 3"
+                    }
+                    td {
+                        Button {
+                            text: "-",
+                            disabled: !(*abilities.read()).advancements.contains(&Some(ability)),
+                            onclick: move |_| {
+                                let idx = (*abilities.read()).advancements.iter().rposition(|adv| *adv == Some(ability));
+                                if let Some(idx) = idx {
+                                    (*abilities.write()).advancements[idx] = None;
+                                }
+                            },
+                        }
+                        " "
+                        Button {
+                            text: "+",
+                            disabled: remaining() == 0 || scores().get(ability).score >= 3,
+                            onclick: move |_| {
+                                let idx = (*abilities.read()).advancements.iter().position(|adv| adv.is_none());
+                                if let Some(idx) = idx {
+                                    (*abilities.write()).advancements[idx] = Some(ability);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn RollAbilities(mut abilities: Signal<fage2e::RollAbilities>) -> Element {
+    let dice = fage2e::RollAbilities::dice();
+    let scores = use_memo(move || {
+        let mut character = fage2e::Character::new();
+        let _ = abilities().apply_all(&mut character);
+        character.mechanical_properties.abilities
+    });
+
+    // Set up signals for the two abilities to swap and an effect to copy them back into
+    // the advancement.
+    let swap1 = use_signal(move || (*abilities.read()).swap.map(|(a, _)| a));
+    let swap2 = use_signal(move || (*abilities.read()).swap.map(|(_, b)| b));
+    use_effect(move || {
+        (*abilities.write()).swap = match (swap1(), swap2()) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        };
+    });
+    let swap_options = use_signal(|| fage2e::Ability::iter().collect());
+
+    use crate::widget::{Button, Selector};
+
+    rsx! {
+        Button {
+            text: "Roll",
+            disabled: false,
+            onclick: move |_| {
+                let rolled = fage2e::RollAbilities::roll();
+                (*abilities.write()).rolls = rolled.rolls;
+            },
+        }
+        table {
+            for (idx, ability) in fage2e::Ability::iter().enumerate() {
+                tr {
+                    td {
+                        padding_right: "5px",
+                        "{ability}: "
+                    }
+                    td {
+                        padding_right: "5px",
+                        input {
+                            r#type: "number",
+                            min: "{dice.min_value()}",
+                            max: "{dice.max_value()}",
+                            value: if let Some(roll) = (*abilities.read()).rolls[idx] {
+                                format!("{roll}")
+                            },
+                            onchange: move |event| {
+                                let val: i32 = event.value().parse().unwrap_or(0);
+                                (*abilities.write()).rolls[idx] = if val < dice.min_value() as i32 {
+                                    None
+                                } else if val < dice.max_value() as i32 {
+                                    Some(val as u8)
+                                } else {
+                                    Some(dice.max_value() as u8)
+                                };
+                            },
+                        }
+                    }
+                    td {
+                        if (*abilities.read()).rolls[idx].is_some() {
+                            "{scores().get(ability).score}"
+                        }
+                    }
+                }
+            }
+        }
+        p { class: "label", "Swap the results of two abilities (optional):" }
+        Selector { options: swap_options, selection: swap1 }
+        Selector { options: swap_options, selection: swap2 }
+    }
+}
+
 #[component]
 pub fn AbilityDetermination(mut abilities: Signal<fage2e::AbilityDetermination>) -> Element {
+    // Set up signals for the chosen mode and each mode's values, and an effect to copy
+    // the chosen mode's values back into the advancement.
+    let mode = use_signal(move || {
+        match abilities.read().deref() {
+            fage2e::AbilityDetermination::NoChoice => None,
+            fage2e::AbilityDetermination::Select(_) => Some(AbilityDeterminationMode::Select),
+            fage2e::AbilityDetermination::Roll(_) => Some(AbilityDeterminationMode::Roll),
+            fage2e::AbilityDetermination::Manual(_) => Some(AbilityDeterminationMode::Manual),
+        }
+    });
+    let mode_options = use_signal(|| AbilityDeterminationMode::iter().collect());
+
+    let select_abilities = use_signal(move || {
+        match abilities.read().deref() {
+            fage2e::AbilityDetermination::Select(abilities) => abilities.clone(),
+            _ => fage2e::SelectAbilities::default(),
+        }
+    });
+    let roll_abilities = use_signal(move || {
+        match abilities.read().deref() {
+            fage2e::AbilityDetermination::Roll(abilities) => abilities.clone(),
+            _ => fage2e::RollAbilities::default(),
+        }
+    });
     let manual_abilities = use_signal(move || {
         match abilities.read().deref() {
             fage2e::AbilityDetermination::Manual(abilities) => abilities.clone(),
@@ -67,20 +239,30 @@ pub fn AbilityDetermination(mut abilities: Signal<fage2e::AbilityDetermination>)
         }
     });
     use_effect(move || {
-        match abilities.write().deref_mut() {
-            fage2e::AbilityDetermination::Manual(abilities) => *abilities = manual_abilities(),
-            // TODO: Other kinds of ability determination.
-            _ => (),
-        }
+        *abilities.write() = match mode() {
+            None => fage2e::AbilityDetermination::NoChoice,
+            Some(AbilityDeterminationMode::Select) => fage2e::AbilityDetermination::Select(select_abilities()),
+            Some(AbilityDeterminationMode::Roll) => fage2e::AbilityDetermination::Roll(roll_abilities()),
+            Some(AbilityDeterminationMode::Manual) => fage2e::AbilityDetermination::Manual(manual_abilities()),
+        };
     });
 
+    use crate::widget::Selector;
+
     rsx! {
         h4 { class: "section-header", "Initial Abilities" }
-        match *abilities.read() {
-            fage2e::AbilityDetermination::Manual(_) => rsx! {
+        Selector { options: mode_options, selection: mode }
+        match mode() {
+            None => rsx! {},
+            Some(AbilityDeterminationMode::Select) => rsx! {
+                SelectAbilities { abilities: select_abilities }
+            },
+            Some(AbilityDeterminationMode::Roll) => rsx! {
+                RollAbilities { abilities: roll_abilities }
+            },
+            Some(AbilityDeterminationMode::Manual) => rsx! {
                 ManuallyEnterAbilities { abilities: manual_abilities }
             },
-            _ => rsx! { "<unsupported>" }
         }
     }
 }
@@ -108,7 +108,7 @@ pub fn Level1AncestrySelections(
 
     // Set up an effect to produce character information for the ancestry-specific selections
     // and to determine ancestry selection completeness.
-    let mut selection_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut selection_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
     let mut subcharacter = use_signal(fage2e::Character::new);
     use_effect(move || {
         let mut character = character();
@@ -117,7 +117,7 @@ pub fn Level1AncestrySelections(
     });
 
     use crate::widget::Selector;
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
     use draak::Level1Selections as DraakLevel1Selections;
     use dwarf::Level1Selections as DwarfLevel1Selections;
     use elf::Level1Selections as ElfLevel1Selections;
@@ -131,6 +131,7 @@ pub fn Level1AncestrySelections(
     rsx! {
         div {
             class: class_for_completeness(selection_status()),
+            title: title_for_completeness(selection_status()),
             h4 { class: "section-header", "Ancestry Selection" }
             Selector { options, selection }
         }
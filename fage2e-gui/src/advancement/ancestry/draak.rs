@@ -19,8 +19,8 @@ pub fn Level1Selections(
     use_effect(move || { (*selections.write()).benefits = benefits(); });
 
     // Set up signals for the sub-advancement states.
-    let mut ability_focus_status = use_signal(|| Result::<bool, ()>::Ok(false));
-    let mut benefits_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut ability_focus_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut benefits_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
 
     // Set up an effect to update sub-advancement states.
     use_effect(move || {
@@ -32,18 +32,20 @@ pub fn Level1Selections(
         benefits_status.set(selections.benefits.apply_all(&mut character));
     });
 
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
     use crate::widget::Selector;
     use crate::advancement::ancestry_benefits::AncestryBenefitSelections;
 
     rsx! {
         div {
             class: class_for_completeness(ability_focus_status()),
+            title: title_for_completeness(ability_focus_status()),
             h4 { class: "section-header", "Select Ability Focus" }
             Selector { options: ability_focus_options, selection: ability_focus }
         }
         div {
             class: class_for_completeness(benefits_status()),
+            title: title_for_completeness(benefits_status()),
             h4 { class: "section-header", "Select Draak Benefits" }
             AncestryBenefitSelections { benefits }
         }
@@ -19,9 +19,20 @@ pub fn Level1Selections(
     use_effect(move || { (*selections.write()).ability_focus = ability_focus(); });
     let ability_focus_options = use_signal(|| fage2e::wildfolk::AbilityFocusSelection::iter().collect());
 
+    let benefits = use_signal(|| (*selections.read()).benefits);
+    use_effect(move || { (*selections.write()).benefits = benefits(); });
+
+    let species_focus = use_signal(|| (*selections.read()).species_focus.focus);
+    use_effect(move || { (*selections.write()).species_focus.focus = species_focus(); });
+    let species_focus_options = use_memo(move || {
+        species().map(|species| species.focus_options().to_vec()).unwrap_or_default()
+    });
+
     // Set up signals for the sub-advancement states.
-    let mut species_status = use_signal(|| Result::<bool, ()>::Ok(false));
-    let mut ability_focus_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut species_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut ability_focus_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut benefits_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut species_focus_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
 
     // Set up an effect to update sub-advancement states.
     use_effect(move || {
@@ -31,21 +42,41 @@ pub fn Level1Selections(
 
         species_status.set(selections.species.apply_all(&mut character));
         ability_focus_status.set(selections.ability_focus.apply_all(&mut character));
+        benefits_status.set(selections.benefits.apply_all(&mut character));
+        species_focus_status.set(selections.species_focus.apply_all(&mut character));
     });
 
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
     use crate::widget::Selector;
+    use crate::advancement::ancestry_benefits::AncestryBenefitSelections;
 
     rsx! {
         div {
             class: class_for_completeness(species_status()),
+            title: title_for_completeness(species_status()),
             h4 { class: "section-header", "Select Species" }
             Selector { options: species_options, selection: species }
         }
         div {
             class: class_for_completeness(ability_focus_status()),
+            title: title_for_completeness(ability_focus_status()),
             h4 { class: "section-header", "Select Ability Focus" }
             Selector { options: ability_focus_options, selection: ability_focus }
         }
+        div {
+            class: class_for_completeness(benefits_status()),
+            title: title_for_completeness(benefits_status()),
+            h4 { class: "section-header", "Select Wildfolk Benefits" }
+            AncestryBenefitSelections { benefits }
+        }
+        div {
+            class: class_for_completeness(species_focus_status()),
+            title: title_for_completeness(species_focus_status()),
+            h4 { class: "section-header", "Select Species Focus" }
+            if species().is_none() {
+                p { class: "label", "Select a species first." }
+            }
+            Selector { options: species_focus_options, selection: species_focus }
+        }
     }
 }
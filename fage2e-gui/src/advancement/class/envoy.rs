@@ -3,6 +3,7 @@ use std::ops::Deref;
 use dioxus::prelude::*;
 
 use fage2e::{self, Advancement};
+use strum::IntoEnumIterator;
 
 #[component]
 pub fn Level1Selections(
@@ -13,8 +14,18 @@ pub fn Level1Selections(
     let weapon_groups = use_signal(|| (*selections.read()).weapon_groups.clone());
     use_effect(move || { (*selections.write()).weapon_groups = weapon_groups(); });
 
+    let starting_talent = use_signal(|| (*selections.read()).starting_talent.talent);
+    use_effect(move || { (*selections.write()).starting_talent.talent = starting_talent(); });
+    let starting_talent_options = use_signal(|| fage2e::envoy::STARTING_TALENTS.to_vec());
+
+    let starting_specialization = use_signal(|| (*selections.read()).starting_specialization.specialization);
+    use_effect(move || { (*selections.write()).starting_specialization.specialization = starting_specialization(); });
+    let starting_specialization_options = use_signal(|| fage2e::envoy::EnvoySpecialization::iter().collect());
+
     // Set up signals for the sub-advancement states.
-    let mut weapon_groups_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut weapon_groups_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut starting_talent_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut starting_specialization_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
 
     // Set up an effect to update sub-advancement states.
     use_effect(move || {
@@ -23,15 +34,31 @@ pub fn Level1Selections(
         let selections = selections.deref();
 
         weapon_groups_status.set(selections.weapon_groups.apply_all(&mut character));
+        starting_talent_status.set(selections.starting_talent.apply_all(&mut character));
+        starting_specialization_status.set(selections.starting_specialization.apply_all(&mut character));
     });
 
     use crate::advancement::InitialWeaponGroups;
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
+    use crate::widget::Selector;
 
     rsx! {
         div {
             class: class_for_completeness(weapon_groups_status()),
+            title: title_for_completeness(weapon_groups_status()),
             InitialWeaponGroups { groups: weapon_groups }
         }
+        div {
+            class: class_for_completeness(starting_talent_status()),
+            title: title_for_completeness(starting_talent_status()),
+            h4 { class: "section-header", "Select Starting Talent" }
+            Selector { options: starting_talent_options, selection: starting_talent }
+        }
+        div {
+            class: class_for_completeness(starting_specialization_status()),
+            title: title_for_completeness(starting_specialization_status()),
+            h4 { class: "section-header", "Select Starting Specialization" }
+            Selector { options: starting_specialization_options, selection: starting_specialization }
+        }
     }
 }
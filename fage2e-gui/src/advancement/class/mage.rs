@@ -1,6 +1,7 @@
 use std::ops::Deref;
 
 use dioxus::prelude::*;
+use strum::IntoEnumIterator;
 
 use fage2e::{self, Advancement};
 
@@ -13,8 +14,30 @@ pub fn Level1Selections(
     let weapon_groups = use_signal(|| (*selections.read()).weapon_groups.clone());
     use_effect(move || { (*selections.write()).weapon_groups = weapon_groups(); });
 
+    let arcana = use_signal(|| {
+        (*selections.read()).arcana.choices.iter().filter_map(|choice| *choice).collect::<Vec<_>>()
+    });
+    use_effect(move || {
+        let arcana = arcana();
+        let mut choices = [None; 2];
+        for (choice, arcana) in choices.iter_mut().zip(arcana) {
+            *choice = Some(arcana);
+        }
+        (*selections.write()).arcana.choices = choices;
+    });
+    let arcana_options = use_signal(|| fage2e::Arcana::iter().collect());
+    let max_arcana = use_signal(|| 2usize);
+
+    let magic_points = use_signal(|| (*selections.read()).magic_points.clone());
+    use_effect(move || { (*selections.write()).magic_points = magic_points(); });
+
     // Set up signals for the sub-advancment states.
-    let mut weapon_groups_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut weapon_groups_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut arcana_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut magic_points_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+
+    // Set up a signal for communicating willpower.
+    let mut willpower = use_signal(|| 0);
 
     // Set up an effect to update sub-advancement states.
     use_effect(move || {
@@ -23,15 +46,35 @@ pub fn Level1Selections(
         let selections = selections.deref();
 
         weapon_groups_status.set(selections.weapon_groups.apply_all(&mut character));
+        arcana_status.set(selections.arcana.apply_all(&mut character));
+
+        willpower.set(character.mechanical_properties.abilities.get(fage2e::Ability::Willpower).score);
+
+        magic_points_status.set(selections.magic_points.apply_all(&mut character));
     });
 
     use crate::advancement::InitialWeaponGroups;
-    use crate::styling::class_for_completeness;
+    use crate::advancement::MagicPointsAdvancement;
+    use crate::styling::{class_for_completeness, title_for_completeness};
+    use crate::widget::MultiSelector;
 
     rsx! {
         div {
             class: class_for_completeness(weapon_groups_status()),
+            title: title_for_completeness(weapon_groups_status()),
             InitialWeaponGroups { groups: weapon_groups }
         }
+        div {
+            class: class_for_completeness(arcana_status()),
+            title: title_for_completeness(arcana_status()),
+            h4 { class: "section-header", "Arcana" }
+            p { class: "label", "Choose two arcana. You learn each one's novice spells." }
+            MultiSelector { options: arcana_options, selections: arcana, max_selections: max_arcana }
+        }
+        div {
+            class: class_for_completeness(magic_points_status()),
+            title: title_for_completeness(magic_points_status()),
+            MagicPointsAdvancement { advancement: magic_points, willpower }
+        }
     }
 }
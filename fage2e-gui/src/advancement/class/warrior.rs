@@ -13,8 +13,18 @@ pub fn Level1Selections(
     let weapon_groups = use_signal(|| (*selections.read()).weapon_groups.clone());
     use_effect(move || { (*selections.write()).weapon_groups = weapon_groups(); });
 
+    let fighting_style = use_signal(|| (*selections.read()).fighting_style.talent);
+    use_effect(move || { (*selections.write()).fighting_style.talent = fighting_style(); });
+    let fighting_style_options = use_signal(|| fage2e::warrior::FIGHTING_STYLES.to_vec());
+
+    let veteran_stunt = use_signal(|| (*selections.read()).veteran_stunt.stunt);
+    use_effect(move || { (*selections.write()).veteran_stunt.stunt = veteran_stunt(); });
+    let veteran_stunt_options = use_signal(fage2e::warrior::veteran_stunt_options);
+
     // Set up signals for the sub-advancment states.
-    let mut weapon_groups_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut weapon_groups_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut fighting_style_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut veteran_stunt_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
 
     // Set up an effect to update sub-advancement states.
     use_effect(move || {
@@ -23,15 +33,31 @@ pub fn Level1Selections(
         let selections = selections.deref();
 
         weapon_groups_status.set(selections.weapon_groups.apply_all(&mut character));
+        fighting_style_status.set(selections.fighting_style.apply_all(&mut character));
+        veteran_stunt_status.set(selections.veteran_stunt.apply_all(&mut character));
     });
 
     use crate::advancement::InitialWeaponGroups;
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
+    use crate::widget::Selector;
 
     rsx! {
         div {
             class: class_for_completeness(weapon_groups_status()),
+            title: title_for_completeness(weapon_groups_status()),
             InitialWeaponGroups { groups: weapon_groups }
         }
+        div {
+            class: class_for_completeness(fighting_style_status()),
+            title: title_for_completeness(fighting_style_status()),
+            h4 { class: "section-header", "Select Fighting Style" }
+            Selector { options: fighting_style_options, selection: fighting_style }
+        }
+        div {
+            class: class_for_completeness(veteran_stunt_status()),
+            title: title_for_completeness(veteran_stunt_status()),
+            h4 { class: "section-header", "Select Veteran Stunt" }
+            Selector { options: veteran_stunt_options, selection: veteran_stunt }
+        }
     }
 }
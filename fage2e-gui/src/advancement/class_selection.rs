@@ -57,7 +57,7 @@ pub fn Level1ClassSelections(
 
     // Set up an effect to produce character information for the class-specific selections
     // and to determine class selection completeness.
-    let mut selection_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut selection_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
     let mut subcharacter = use_signal(fage2e::Character::new);
     use_effect(move || {
         let mut character = character();
@@ -70,11 +70,12 @@ pub fn Level1ClassSelections(
     use crate::advancement::class::mage::Level1Selections as MageLevel1Selections;
     use crate::advancement::class::rogue::Level1Selections as RogueLevel1Selections;
     use crate::advancement::class::warrior::Level1Selections as WarriorLevel1Selections;
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
 
     rsx! {
         div {
             class: class_for_completeness(selection_status()),
+            title: title_for_completeness(selection_status()),
             h4 { class: "section-header", "Class Selection" }
             Selector { options, selection }
         }
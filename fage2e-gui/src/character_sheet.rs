@@ -13,6 +13,8 @@ pub fn CharacterSheet(character: ReadOnlySignal<Character>) -> Element {
 
     let bgrnd = character_ref.flavor.background.clone().unwrap_or("".to_owned());
     let social_class = character_ref.flavor.social_class.clone().unwrap_or("".to_owned());
+    let second_bgrnd = character_ref.flavor.second_background.clone();
+    let second_social_class = character_ref.flavor.second_social_class.clone();
     let class = character_ref.mechanical_properties.class.clone().map(|c| c.to_string()).unwrap_or("".to_owned());
     let ancestry = character_ref.mechanical_properties.ancestry.clone().map(|a| a.to_string()).unwrap_or("".to_owned());
     rsx! {
@@ -28,6 +30,12 @@ pub fn CharacterSheet(character: ReadOnlySignal<Character>) -> Element {
                         b {"Name:"} " {character_ref.flavor.name}" br {}
                         b {"Background:"} " {bgrnd}" br {}
                         b {"Social Class:"} " {social_class}" br {}
+                        if let Some(second_bgrnd) = second_bgrnd {
+                            b {"Second Background:"} " {second_bgrnd}" br {}
+                        }
+                        if let Some(second_social_class) = second_social_class {
+                            b {"Second Social Class:"} " {second_social_class}" br {}
+                        }
                     }
                     AbilityTable { character }
                 }
@@ -43,6 +51,9 @@ pub fn CharacterSheet(character: ReadOnlySignal<Character>) -> Element {
                 }
             }
             RangeWeapons { character }
+            if !character_ref.mechanical_properties.spells.is_empty() {
+                Spells { character }
+            }
         }
     }
 }
@@ -118,8 +129,12 @@ fn CoreStats(character: ReadOnlySignal<Character>) -> Element {
 
     let max_health_details = character.max_health();
     let speed_details = character.speed_yards();
-    let defense_details = character.defense();
+    let melee_defense_details = character.defense_vs(AttackType::Melee);
+    let ranged_defense_details = character.defense_vs(AttackType::Ranged);
     let armor_details = character.armor();
+    let magic_points_details = character.mechanical_properties.class
+        .and_then(|class| class.initial_base_magic_points())
+        .map(|_| character.max_magic_points());
 
     rsx! {
         table {
@@ -131,14 +146,24 @@ fn CoreStats(character: ReadOnlySignal<Character>) -> Element {
                     "SPEED"
                 }
                 th {
-                    title: "What an enemy has to beat to hit you.",
-                    "DEFENSE"
+                    title: "What an enemy has to beat to hit you in melee.",
+                    "DEFENSE (MELEE)"
+                }
+                th {
+                    title: "What an enemy has to beat to hit you at range.",
+                    "DEFENSE (RANGED)"
                 }
                 th {
                     title: "Standard damage reduction.",
                     "ARMOR"
                 }
                 th { "HEALTH" }
+                if magic_points_details.is_some() {
+                    th {
+                        title: "How much magic you can cast before resting.",
+                        "MAGIC"
+                    }
+                }
             }
             tr {
                 th {
@@ -146,8 +171,12 @@ fn CoreStats(character: ReadOnlySignal<Character>) -> Element {
                     "{speed_details.final_value()}"
                 }
                 th {
-                    title: "{format_value_modifiers(&defense_details)}",
-                    "{defense_details.final_value()}"
+                    title: "{format_value_modifiers(&melee_defense_details)}",
+                    "{melee_defense_details.final_value()}"
+                }
+                th {
+                    title: "{format_value_modifiers(&ranged_defense_details)}",
+                    "{ranged_defense_details.final_value()}"
                 }
                 th {
                     title: "{format_value_modifiers(&armor_details)}",
@@ -157,6 +186,12 @@ fn CoreStats(character: ReadOnlySignal<Character>) -> Element {
                     title: "{format_value_modifiers(&max_health_details)}",
                     "{max_health_details.final_value()}"
                 }
+                if let Some(magic_points_details) = &magic_points_details {
+                    th {
+                        title: "{format_value_modifiers(magic_points_details)}",
+                        "{magic_points_details.final_value()}"
+                    }
+                }
             }
         }
         table {
@@ -197,12 +232,28 @@ fn CoreStats(character: ReadOnlySignal<Character>) -> Element {
 
 #[component]
 fn Powers(character: ReadOnlySignal<Character>) -> Element {
+    let mut talents: Vec<_> = (*character.read()).mechanical_properties.talents.iter()
+        .map(|(talent, degree)| (*talent, *degree))
+        .collect();
+    talents.sort_by_key(|(talent, _)| talent.name());
+    let mut stunts: Vec<_> = (*character.read()).mechanical_properties.stunts.iter()
+        .map(|(stunt, source)| (*stunt, *source))
+        .collect();
+    stunts.sort_by_key(|(stunt, _)| stunt.name());
+    let stunts: Vec<_> = stunts.into_iter()
+        .map(|(stunt, source)| {
+            let character = character.read();
+            (stunt, source, character.stunt_sp_cost(stunt), character.stunt_damage_roll(stunt))
+        })
+        .collect();
+    let resistance_test = (*character.read()).magical_resistance_test();
+
     rsx! {
         table {
             class: "stat-table",
             tr {
                 class: "heading",
-                th { "POWERS, TALENTS, SPECIALIZATIONS" }
+                th { "POWERS, TALENTS, SPECIALIZATIONS, STUNTS" }
             }
             tr {
                 td {
@@ -216,6 +267,37 @@ fn Powers(character: ReadOnlySignal<Character>) -> Element {
                                 "{power.name()}"
                             }
                         }
+                        for (talent, degree) in talents {
+                            li {
+                                title: "{talent.description()}",
+                                "{talent} ({degree})"
+                            }
+                        }
+                        if let Some(test) = &resistance_test {
+                            li {
+                                title: "{format_dice_roll_modifiers(test)}",
+                                "Magical Resistance Test: {format_dice_roll(test)}"
+                            }
+                        }
+                        for (stunt, source, cost, damage) in stunts {
+                            li {
+                                title: "{stunt.description()} ({source})",
+                                "{stunt.stunt_type()} Stunt: {stunt} "
+                                span {
+                                    title: "{format_value_modifiers(&cost)}",
+                                    "({cost.final_value()} SP)"
+                                }
+                                if let Some(area) = stunt.area() {
+                                    ", {area}"
+                                }
+                                if let Some(damage) = &damage {
+                                    span {
+                                        title: "{format_dice_roll_modifiers(damage)}",
+                                        ", {format_dice_roll(damage)} damage"
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -233,6 +315,13 @@ fn MeleeWeapons(character: ReadOnlySignal<Character>) -> Element {
     weapon_training.sort();
     let weapon_training = weapon_training.join(", ");
 
+    let weapons: Vec<_> = character.equipment.weapons.iter()
+        .filter(|weapon| weapon.properties().missile_properties.is_none())
+        .map(|weapon| (*weapon, character.attack_roll(*weapon), character.damage_roll(*weapon)))
+        .collect();
+    let natural_weapon = character.natural_weapon()
+        .map(|weapon| (weapon, character.natural_weapon_attack_roll(), character.natural_weapon_damage_roll(weapon)));
+
     rsx! {
         table {
             class: "stat-table",
@@ -257,30 +346,34 @@ fn MeleeWeapons(character: ReadOnlySignal<Character>) -> Element {
                     "ATTACK ROLL"
                 }
                 th {
-                    title: "The dice to roll to determine how much damage you deal.",
+                    title: "The dice to roll to determine how much damage you deal. Bonuses in parentheses only apply sometimes.",
                     "DAMAGE ROLL"
                 }
             }
-            tr {
-                td { "TODO" }
-                td {
-                    title: "TODO",
-                    "TODO"
-                }
-                td {
-                    title: "TODO",
-                    "TODO"
+            for (weapon, attack, damage) in weapons {
+                tr {
+                    td { "{weapon}" }
+                    td {
+                        title: "{format_dice_roll_modifiers(&attack)}",
+                        "{format_dice_roll(&attack)}"
+                    }
+                    td {
+                        title: "{format_dice_roll_modifiers(&damage)}",
+                        "{format_dice_roll(&damage)}"
+                    }
                 }
             }
-            tr {
-                td { "TODO" }
-                td {
-                    title: "TODO",
-                    "TODO"
-                }
-                td {
-                    title: "TODO",
-                    "TODO"
+            if let Some((weapon, attack, damage)) = natural_weapon {
+                tr {
+                    td { "{weapon} (natural)" }
+                    td {
+                        title: "{format_dice_roll_modifiers(&attack)}",
+                        "{format_dice_roll(&attack)}"
+                    }
+                    td {
+                        title: "{format_dice_roll_modifiers(&damage)}",
+                        "{format_dice_roll(&damage)}"
+                    }
                 }
             }
         }
@@ -290,6 +383,15 @@ fn MeleeWeapons(character: ReadOnlySignal<Character>) -> Element {
 
 #[component]
 fn RangeWeapons(character: ReadOnlySignal<Character>) -> Element {
+    let character = character.read();
+    let character = character.deref();
+
+    let weapons: Vec<_> = character.equipment.weapons.iter()
+        .filter_map(|weapon| weapon.properties().missile_properties.map(|missile| (*weapon, missile)))
+        .map(|(weapon, missile)| (weapon, missile, character.attack_roll(weapon), character.damage_roll(weapon)))
+        .collect();
+    let arcane_blast = character.arcane_blast_attack_roll().zip(character.arcane_blast_damage_roll());
+
     rsx !{
         table {
             class: "stat-table",
@@ -301,49 +403,126 @@ fn RangeWeapons(character: ReadOnlySignal<Character>) -> Element {
                     "ATTACK ROLL"
                 }
                 th {
-                    title: "The dice to roll to determine how much damage you deal.",
+                    title: "The dice to roll to determine how much damage you deal. Bonuses in parentheses only apply sometimes.",
                     "DAMAGE ROLL"
                 }
                 th {
-                    title: "TODO",
+                    title: "How far away in yards you can attack without penalty.",
                     "SHORT RANGE"
                 }
                 th {
-                    title: "TODO",
+                    title: "The farthest away in yards you can attack at all.",
                     "LONG RANGE"
                 }
                 th {
-                    title: "TODO",
+                    title: "What kind of action it takes to reload.",
                     "RELOAD TIME"
                 }
             }
-            tr {
-                td { "TODO" }
-                td {
-                    title: "TODO",
-                    "TODO"
+            for (weapon, missile, attack, damage) in weapons {
+                tr {
+                    td { "{weapon}" }
+                    td {
+                        title: "{format_dice_roll_modifiers(&attack)}",
+                        "{format_dice_roll(&attack)}"
+                    }
+                    td {
+                        title: "{format_dice_roll_modifiers(&damage)}",
+                        "{format_dice_roll(&damage)}"
+                    }
+                    td { "{missile.short_range_yards}" }
+                    td {
+                        match missile.long_range_yards {
+                            Some(yards) => format!("{yards}"),
+                            None => "N/A".to_owned(),
+                        }
+                    }
+                    td {
+                        if missile.reload_is_major_action { "Major Action" } else { "Minor Action" }
+                    }
                 }
-                td {
-                    title: "TODO",
-                    "TODO"
+            }
+            if let Some((attack, damage)) = arcane_blast {
+                tr {
+                    td { "Arcane Blast" }
+                    td {
+                        title: "{format_dice_roll_modifiers(&attack)}",
+                        "{format_dice_roll(&attack)}"
+                    }
+                    td {
+                        title: "{format_dice_roll_modifiers(&damage)}",
+                        "{format_dice_roll(&damage)}"
+                    }
+                    td { "{mage::ARCANE_BLAST_RANGE_YARDS}" }
+                    td { "N/A" }
+                    td { "N/A" }
                 }
-                td { "TODO" }
-                td { "TODO" }
-                td { "TODO" }
             }
+        }
+    }
+}
+
+
+#[component]
+fn Spells(character: ReadOnlySignal<Character>) -> Element {
+    let mut spells: Vec<_> = (*character.read()).mechanical_properties.spells.iter().copied().collect();
+    spells.sort_by_key(|spell| (spell.arcana().name(), spell.name()));
+    let spells: Vec<_> = spells.into_iter().map(|spell| {
+        let character = character.read();
+        (spell, character.spellpower(spell.arcana()), character.casting_test(spell))
+    }).collect();
+
+    rsx! {
+        table {
+            class: "stat-table",
             tr {
-                td { "TODO" }
-                td {
-                    title: "TODO",
-                    "TODO"
+                class: "heading",
+                th { "SPELL" }
+                th { "ARCANA" }
+                th {
+                    title: "What targets have to beat to resist the spell.",
+                    "SPELLPOWER"
                 }
-                td {
-                    title: "TODO",
-                    "TODO"
+                th {
+                    title: "The roll to cast the spell.",
+                    "CASTING TEST"
+                }
+                th {
+                    title: "Magic point cost.",
+                    "MP"
+                }
+                th { "CASTING TIME" }
+                th {
+                    title: "Target number for the casting test.",
+                    "TN"
+                }
+                th {
+                    title: "The focus targets use to resist the spell.",
+                    "TEST"
+                }
+            }
+            for (spell, spellpower, casting_test) in spells {
+                tr {
+                    td { "{spell}" }
+                    td { "{spell.arcana()}" }
+                    td {
+                        title: "{format_value_modifiers(&spellpower)}",
+                        "{spellpower.final_value()}"
+                    }
+                    td {
+                        title: "{format_dice_roll_modifiers(&casting_test)}",
+                        "{format_dice_roll(&casting_test)}"
+                    }
+                    td { "{spell.properties().mp_cost}" }
+                    td { "{spell.properties().casting_time}" }
+                    td { "{spell.properties().target_number}" }
+                    td {
+                        match spell.properties().test {
+                            Some(focus) => format!("{} ({})", focus.ability(), focus),
+                            None => "None".to_owned(),
+                        }
+                    }
                 }
-                td { "TODO" }
-                td { "TODO" }
-                td { "TODO" }
             }
         }
     }
@@ -366,5 +545,64 @@ fn format_value_modifiers(value: &fage2e::Value) -> String {
             details.push_str(&format!("\n- {} ({})", -additive.value, additive.source));
         }
     }
+    if let Some(source) = &value.modifiers.halved {
+        details.push_str(&format!("\n/ 2 ({})", source));
+    }
+    details
+}
+
+
+fn format_dice_roll(roll: &fage2e::DiceRoll) -> String {
+    // Halving applies to the whole roll, so work out the unhalved total first.
+    let mut unhalved = roll.clone();
+    unhalved.modifiers.halved = None;
+    let total = DiceWithMod { dice: roll.dice(), modifier: unhalved.final_value(0) as i8 };
+    let mut text = if roll.modifiers.halved.is_some() {
+        format!("({total}) / 2")
+    }
+    else {
+        format!("{total}")
+    };
+    for conditional in roll.modifiers.conditional.iter() {
+        text.push_str(&format!(" (+{})", format_conditional_value(&conditional.value)));
+    }
+    text
+}
+
+
+fn format_conditional_value(value: &DiceWithMod) -> String {
+    // Conditional bonuses are usually just extra dice, so leave off a "+ 0".
+    if value.modifier == 0 {
+        format!("{}", value.dice)
+    }
+    else {
+        format!("{value}")
+    }
+}
+
+
+fn format_dice_roll_modifiers(roll: &fage2e::DiceRoll) -> String {
+    let base = if let Some(override_) = &roll.modifiers.override_ {
+        override_
+    } else {
+        &roll.base
+    };
+    let mut details = format!("Base: {} ({})", base.value, base.source);
+    for additive in roll.modifiers.additive.iter() {
+        if additive.value >= 0 {
+            details.push_str(&format!("\n+ {} ({})", additive.value, additive.source));
+        }
+        else {
+            details.push_str(&format!("\n- {} ({})", -additive.value, additive.source));
+        }
+    }
+    if let Some(source) = &roll.modifiers.halved {
+        details.push_str(&format!("\n/ 2 ({})", source));
+    }
+    for conditional in roll.modifiers.conditional.iter() {
+        details.push_str(&format!(
+            "\n+ {} ({}, when it applies)", format_conditional_value(&conditional.value), conditional.source
+        ));
+    }
     details
 }
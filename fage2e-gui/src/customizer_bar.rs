@@ -24,56 +24,172 @@ pub fn CustomizerBar(mut character: Signal<fage2e::Character>) -> Element
     let mut level1_storage = use_local_storage("level1", fage2e::Level1::default);
     let level1 = use_signal(move || level1_storage.get());
     use_effect(move || level1_storage.set(level1()));
-    // TODO: level2, etc.
 
-    // Next: Signals indicating whether each level advancement is ok and/or fully filled out.
-    let mut level1_status = use_signal(|| Result::<bool, ()>::Ok(false));
-    // TODO: level2, etc.
+    // Levels 2 through 20 all have the same shape, so they're kept together in one list.
+    let mut levels_storage = use_local_storage("levels", Vec::<fage2e::LevelN>::new);
+    let mut levels = use_signal(move || levels_storage.get());
+    use_effect(move || levels_storage.set(levels()));
+
+    // Equipment isn't tied to any particular level, so it's kept separately.
+    let mut equipment_storage = use_local_storage("equipment", fage2e::CharacterEquipment::default);
+    let equipment = use_signal(move || equipment_storage.get());
+    use_effect(move || equipment_storage.set(equipment()));
+
+    // Next: Signals listing what's incomplete or invalid in each level advancement.
+    let mut level1_report = use_signal(fage2e::ValidationReport::default);
+    let mut levels_reports = use_signal(Vec::<fage2e::ValidationReport>::new);
 
     // Finally: Signals for the character state at each level.
-    let level0_character = use_signal(|| fage2e::Character::new());
+    let mut level0_character = use_signal(|| fage2e::Character::new());
+    use_effect(move || { (*level0_character.write()).equipment = equipment(); });
     let level1_character = use_memo(move || {
         let mut character = level0_character();
-        level1_status.set(level1().apply_all(&mut character));
+        level1_report.set(fage2e::validate("level1", &level1(), &character));
+        let _ = level1().apply_all(&mut character);
         character
     });
-    // TODO: level2, etc.
+    let levels_characters = use_memo(move || {
+        let mut character = level1_character();
+        let mut reports = Vec::new();
+        let mut characters = Vec::new();
+        for level in levels().iter() {
+            reports.push(fage2e::validate(&format!("level{}", level.level), level, &character));
+            let _ = level.apply_all(&mut character);
+            characters.push(character.clone());
+        }
+        levels_reports.set(reports);
+        characters
+    });
+
+    // The advancements available at later levels depend on the class chosen at level 1,
+    // so rebuild any that were laid out for a different class.
+    use_effect(move || {
+        let class = match level1_character().mechanical_properties.class {
+            None => return,
+            Some(class) => class,
+        };
+        let up_to_date = {
+            let levels = levels.peek();
+            levels.len() == (fage2e::LevelN::MIN_LEVEL..=fage2e::LevelN::MAX_LEVEL).len()
+                && levels.iter().all(|level| level.class == class)
+        };
+        if !up_to_date {
+            let rebuilt = (fage2e::LevelN::MIN_LEVEL..=fage2e::LevelN::MAX_LEVEL).map(|lvl| {
+                levels.peek().iter()
+                    .find(|level| level.level == lvl && level.class == class)
+                    .cloned()
+                    .unwrap_or_else(|| fage2e::LevelN::new(lvl, class))
+            }).collect();
+            levels.set(rebuilt);
+        }
+    });
 
     // Set up an effect to update the current character settings based on the currently
     // selected level.
-    let character_levels = [
-        level1_character,
-    ];
     let mut level_select = use_signal(|| 1u8);
+    let mut equipment_select = use_signal(|| false);
     use_effect(move || {
         character.set(match level_select() {
-            lvl if lvl == 1 => { character_levels[(lvl - 1) as usize]() }
-            // We should never hit this, but just in case...
-            _ => { level0_character() }
+            1 => { level1_character() }
+            lvl => {
+                match levels_characters().get((lvl - fage2e::LevelN::MIN_LEVEL) as usize) {
+                    Some(character) => character.clone(),
+                    // The later levels haven't been laid out yet.
+                    None => { level1_character() }
+                }
+            }
+        });
+    });
+
+    // Set up an effect to track the character as of the level before the currently
+    // selected one, which is what that level's advancements apply to.
+    let mut previous_character = use_signal(|| fage2e::Character::new());
+    use_effect(move || {
+        previous_character.set(match level_select() {
+            0 | 1 => { level0_character() }
+            2 => { level1_character() }
+            lvl => {
+                match levels_characters().get((lvl - 1 - fage2e::LevelN::MIN_LEVEL) as usize) {
+                    Some(character) => character.clone(),
+                    None => { level1_character() }
+                }
+            }
         });
     });
 
-    use crate::level::Level1;
+    // Set up a memo for the report of the currently selected level, and a helper for
+    // the badge on each level's tab.
+    let selected_report = use_memo(move || {
+        match level_select() {
+            1 => level1_report(),
+            lvl => levels_reports().get((lvl - fage2e::LevelN::MIN_LEVEL) as usize).cloned().unwrap_or_default(),
+        }
+    });
+    let badge_for = move |lvl: u8| {
+        let status = match lvl {
+            1 => level1_report().status(),
+            lvl => match levels_reports().get((lvl - fage2e::LevelN::MIN_LEVEL) as usize) {
+                Some(report) => report.status(),
+                // The later levels haven't been laid out yet.
+                None => return "",
+            },
+        };
+        match status {
+            Ok(true) => "level-complete",
+            Ok(false) => "level-incomplete",
+            Err(_) => "level-error",
+        }
+    };
+
+    use crate::equipment::EquipmentPanel;
+    use crate::level::{Level1, LevelN};
+    use crate::validation::ValidationSummary;
 
     rsx! {
         div {
             class: "level-selector",
             for i in 1..=20 {
                 span {
-                    class: if level_select() == i { "selected" } else { "unselected" },
-                    onclick: move |_| { level_select.set(i); },
+                    class: if !equipment_select() && level_select() == i { "selected" } else { "unselected" },
+                    class: badge_for(i),
+                    onclick: move |_| {
+                        level_select.set(i);
+                        equipment_select.set(false);
+                    },
                     "Level {i}"
                 }
             }
+            span {
+                class: if equipment_select() { "selected" } else { "unselected" },
+                onclick: move |_| { equipment_select.set(true); },
+                "Equipment"
+            }
         }
         div {
             class: "customizer-bar",
-            match level_select() {
-                1 => rsx! { Level1 { character: level0_character, level1 } },
-                _ => rsx! {
-                    h3 { class: "title", "ERROR" }
-                    p { "How did you select an invalid level?" }
-                },
+            // The equipment panel shows the character as of the currently selected level.
+            if equipment_select() {
+                EquipmentPanel { character, equipment }
+            } else {
+                ValidationSummary { level: level_select(), report: selected_report }
+                hr {}
+                match level_select() {
+                    1 => rsx! { Level1 { character: level0_character, level1 } },
+                    lvl @ 2..=20 if levels.read().is_empty() => rsx! {
+                        h3 { class: "title", "Level {lvl}" }
+                        p { "Choose a class at level 1 first." }
+                    },
+                    lvl @ 2..=20 => rsx! {
+                        // Key on the level so that switching levels rebuilds the customizer.
+                        for index in [(lvl - fage2e::LevelN::MIN_LEVEL) as usize] {
+                            LevelN { key: "{lvl}", character: previous_character, levels, index }
+                        }
+                    },
+                    _ => rsx! {
+                        h3 { class: "title", "ERROR" }
+                        p { "How did you select an invalid level?" }
+                    },
+                }
             }
         }
     }
@@ -0,0 +1,115 @@
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+use fage2e;
+
+/// Component for browsing the equipment catalogs and managing what the character carries.
+///
+/// Args:
+/// * character: The character the equipment is for. Used to flag equipment the
+///   character can't use well.
+/// * equipment: The character's equipment.
+#[component]
+pub fn EquipmentPanel(
+    character: ReadOnlySignal<fage2e::Character>,
+    mut equipment: Signal<fage2e::CharacterEquipment>,
+) -> Element {
+    // Set up signals for the armor and shield slots and effects to copy them back into
+    // the equipment.
+    let armor = use_signal(move || (*equipment.read()).armor);
+    use_effect(move || { (*equipment.write()).armor = armor(); });
+    let armor_options = use_signal(|| fage2e::Armor::iter().collect());
+
+    let shield = use_signal(move || (*equipment.read()).shield);
+    use_effect(move || { (*equipment.write()).shield = shield(); });
+    let shield_options = use_signal(|| fage2e::Shield::iter().collect());
+
+    let mut wielded = use_signal(move || (*equipment.read()).wielded);
+    use_effect(move || { (*equipment.write()).wielded = wielded(); });
+    let wielded_options = use_memo(move || {
+        let mut weapons = Vec::new();
+        for weapon in (*equipment.read()).weapons.iter() {
+            if !weapons.contains(weapon) {
+                weapons.push(*weapon);
+            }
+        }
+        weapons
+    });
+
+    let strength = (*character.read()).mechanical_properties.abilities.get(fage2e::Ability::Strength).score;
+    let lacks_strength = move |min_strength: Option<i8>| {
+        min_strength.is_some_and(|min_strength| strength < min_strength)
+    };
+
+    use crate::widget::{Button, Selector};
+
+    rsx! {
+        h3 { class: "title", "Equipment" }
+        div {
+            h4 { class: "section-header", "Armor" }
+            Selector { options: armor_options, selection: armor }
+            if let Some(armor) = armor() {
+                if lacks_strength(armor.properties().min_strength) {
+                    p { class: "label error", "Not strong enough for {armor}." }
+                }
+            }
+        }
+        div {
+            h4 { class: "section-header", "Shield" }
+            Selector { options: shield_options, selection: shield }
+            if let Some(shield) = shield() {
+                if lacks_strength(shield.properties().min_strength) {
+                    p { class: "label error", "Not strong enough for {shield}." }
+                }
+            }
+        }
+        hr {}
+        div {
+            h4 { class: "section-header", "In Hand" }
+            Selector { options: wielded_options, selection: wielded }
+        }
+        h4 { class: "section-header", "Weapons" }
+        for group in fage2e::WeaponGroup::iter() {
+            div {
+                key: "{group}",
+                h4 {
+                    "{group}"
+                    if !(*character.read()).mechanical_properties.weapon_training.contains(&group) {
+                        span { class: "label incomplete", " (untrained)" }
+                    }
+                }
+                for weapon in group.weapons() {
+                    p {
+                        key: "{weapon}",
+                        class: "label",
+                        class: if lacks_strength(weapon.properties().min_strength) { "incomplete" },
+                        title: match weapon.properties().min_strength {
+                            Some(min_strength) => format!("Minimum strength: {min_strength}"),
+                            None => "No minimum strength".to_owned(),
+                        },
+                        "{weapon} ({(*equipment.read()).weapons.iter().filter(|w| **w == weapon).count()}) "
+                        Button {
+                            text: "Add",
+                            disabled: false,
+                            onclick: move |_| { (*equipment.write()).weapons.push(weapon); },
+                        }
+                        " "
+                        Button {
+                            text: "Remove",
+                            disabled: !(*equipment.read()).weapons.contains(&weapon),
+                            onclick: move |_| {
+                                let idx = (*equipment.read()).weapons.iter().position(|w| *w == weapon);
+                                if let Some(idx) = idx {
+                                    (*equipment.write()).weapons.remove(idx);
+                                }
+                                if !(*equipment.read()).weapons.contains(&weapon) && wielded() == Some(weapon) {
+                                    wielded.set(None);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::ops::Deref;
 
 use dioxus::prelude::*;
+use strum::IntoEnumIterator;
 use tracing::info;
 
 use fage2e;
@@ -13,15 +14,7 @@ pub fn Level1(character: ReadOnlySignal<fage2e::Character>, mut level1: Signal<f
     let name = use_signal(move || { (*level1.read()).name.clone() });
     use_effect(move || { (*level1.write()).name = name(); });
 
-    let abilities = use_signal(move || {
-        // TODO: Allow the ability to select ability determination style.
-        let level1 = level1.read();
-        let level1 = level1.deref();
-        match level1.abilities {
-            fage2e::AbilityDetermination::Manual(_) => level1.abilities.clone(),
-            _ => fage2e::AbilityDetermination::Manual(fage2e::ManuallyEnterAbilities::default()),
-        }
-    });
+    let abilities = use_signal(move || { (*level1.read()).abilities.clone() });
     use_effect(move || { (*level1.write()).abilities = abilities(); });
 
     let class_selections = use_signal(move || { (*level1.read()).class.clone() });
@@ -34,10 +27,10 @@ pub fn Level1(character: ReadOnlySignal<fage2e::Character>, mut level1: Signal<f
     use_effect(move || { (*level1.write()).health = health(); });
 
     // Set up signals for the sub-advancement states.
-    let mut name_status = use_signal(|| Result::<bool, ()>::Ok(false));
-    let mut abilities_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut name_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut abilities_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
     let mut class_selections_character = use_signal(fage2e::Character::new);
-    let mut health_status = use_signal(|| Result::<bool, ()>::Ok(false));
+    let mut health_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
 
     // Set up a signal for communicating constitution.
     let mut constitution = use_signal(|| 0);
@@ -67,16 +60,18 @@ pub fn Level1(character: ReadOnlySignal<fage2e::Character>, mut level1: Signal<f
     use crate::advancement::Level1ClassSelections;
     use crate::advancement::Level1AncestrySelections;
     use crate::advancement::DiceBasedHealthAdvancement;
-    use crate::styling::class_for_completeness;
+    use crate::styling::{class_for_completeness, title_for_completeness};
 
     rsx! {
         h3 { class: "title", "Level 1" }
         div {
             class: class_for_completeness(name_status()),
+            title: title_for_completeness(name_status()),
             SelectName { name }
         }
         div {
             class: class_for_completeness(abilities_status()),
+            title: title_for_completeness(abilities_status()),
             AbilityDetermination { abilities }
         }
         hr {}
@@ -84,6 +79,7 @@ pub fn Level1(character: ReadOnlySignal<fage2e::Character>, mut level1: Signal<f
         hr {}
         div {
             class: class_for_completeness(health_status()),
+            title: title_for_completeness(health_status()),
             DiceBasedHealthAdvancement { advancement: health, constitution }
         }
         hr {}
@@ -91,3 +87,306 @@ pub fn Level1(character: ReadOnlySignal<fage2e::Character>, mut level1: Signal<f
         Level1AncestrySelections { ancestry_selections, character: class_selections_character}
     }
 }
+
+#[component]
+pub fn LevelN(
+    character: ReadOnlySignal<fage2e::Character>,
+    mut levels: Signal<Vec<fage2e::LevelN>>,
+    index: usize,
+) -> Element {
+    // Set up a signal for this level's advancement and an effect to copy it back into
+    // the list of level advancements.
+    let mut level = use_signal(move || (*levels.read())[index].clone());
+    use_effect(move || { (*levels.write())[index] = level(); });
+
+    // Set up signals for the sub-advancement values and effects to copy them back into
+    // the level advancement.
+    let ability = use_signal(move || (*level.read()).ability.ability());
+    use_effect(move || { *(*level.write()).ability.ability_mut() = ability(); });
+    let ability_options = use_signal(move || {
+        let level = level.read();
+        level.ability.options(level.class).to_vec()
+    });
+
+    let focus = use_signal(move || (*level.read()).focus.focus());
+    use_effect(move || { *(*level.write()).focus.focus_mut() = focus(); });
+    let focus_options = use_signal(move || {
+        let level = level.read();
+        level.focus.ability_options(level.class).iter().flat_map(|a| a.focuses()).collect()
+    });
+
+    let talent = use_signal(move || (*level.read()).talent.as_ref().and_then(|adv| adv.talent));
+    use_effect(move || {
+        let talent = talent();
+        if let Some(adv) = &mut (*level.write()).talent {
+            adv.talent = talent;
+        }
+    });
+    let talent_options = use_signal(move || {
+        let class = (*level.read()).class;
+        fage2e::Talent::iter().filter(|t| t.classes().contains(&class)).collect()
+    });
+
+    let specialization = use_signal(move || {
+        (*level.read()).specialization.as_ref().and_then(|adv| adv.specialization)
+    });
+    use_effect(move || {
+        let specialization = specialization();
+        if let Some(adv) = &mut (*level.write()).specialization {
+            adv.specialization = specialization;
+        }
+    });
+    let specialization_options = use_signal(move || {
+        fage2e::Specialization::for_class((*level.read()).class)
+    });
+
+    let specialization_talent = use_signal(move || {
+        (*level.read()).specialization.as_ref().and_then(|adv| adv.talent)
+    });
+    use_effect(move || {
+        let talent = specialization_talent();
+        if let Some(adv) = &mut (*level.write()).specialization {
+            adv.talent = talent;
+        }
+    });
+
+    let stunt = use_signal(move || (*level.read()).stunt.as_ref().and_then(|adv| adv.stunt));
+    use_effect(move || {
+        let stunt = stunt();
+        if let Some(adv) = &mut (*level.write()).stunt {
+            adv.stunt = stunt;
+        }
+    });
+    let stunt_options = use_signal(move || {
+        let class = (*level.read()).class;
+        fage2e::Stunt::iter().filter(|s| s.classes().contains(&class)).collect()
+    });
+
+    let out_of_class_stunt = use_signal(move || {
+        (*level.read()).out_of_class_stunt.as_ref().and_then(|adv| adv.stunt)
+    });
+    use_effect(move || {
+        let stunt = out_of_class_stunt();
+        if let Some(adv) = &mut (*level.write()).out_of_class_stunt {
+            adv.stunt = stunt;
+        }
+    });
+    let out_of_class_stunt_options = use_signal(move || {
+        fage2e::Stunt::iter().filter(|s| s.ancestry().is_none()).collect()
+    });
+
+    let spell = use_signal(move || (*level.read()).spell.as_ref().and_then(|adv| adv.spell));
+    use_effect(move || {
+        let spell = spell();
+        if let Some(adv) = &mut (*level.write()).spell {
+            adv.spell = spell;
+        }
+    });
+    let spell_options = use_memo(move || {
+        let level = (*level.read()).level;
+        let character = character.read();
+        let magic = &character.mechanical_properties;
+        fage2e::Spell::iter()
+            .filter(|s| s.min_level() <= level)
+            .filter(|s| magic.arcana.contains(&s.arcana()) && !magic.spells.contains(s))
+            .collect::<Vec<_>>()
+    });
+
+    let health = use_signal(move || {
+        match &(*level.read()).health {
+            fage2e::HealthAdvancement::Dice(adv) => adv.clone(),
+            _ => fage2e::DiceBasedHealthAdvancement::default(),
+        }
+    });
+    use_effect(move || {
+        let health = health();
+        if let fage2e::HealthAdvancement::Dice(adv) = &mut (*level.write()).health {
+            *adv = health;
+        }
+    });
+
+    let magic_points = use_signal(move || {
+        (*level.read()).magic_points.clone().unwrap_or_default()
+    });
+    use_effect(move || {
+        let magic_points = magic_points();
+        if let Some(adv) = &mut (*level.write()).magic_points {
+            *adv = magic_points;
+        }
+    });
+
+    // Set up signals for the sub-advancement states.
+    let mut level_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut ability_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut focus_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut health_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut magic_points_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut talent_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut specialization_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut stunt_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut out_of_class_stunt_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+    let mut spell_status = use_signal(|| Result::<bool, fage2e::AdvancementError>::Ok(false));
+
+    // Set up signals for communicating constitution and willpower.
+    let mut constitution = use_signal(|| 0);
+    let mut willpower = use_signal(|| 0);
+
+    // Set up an effect to update sub-advancement states and calculate constitution and willpower.
+    use_effect(move || {
+        let mut character = character();
+        let level = level.read();
+        let level = level.deref();
+
+        level_status.set(level.apply_self(&mut character));
+        ability_status.set(level.ability.apply_all(&mut character));
+        focus_status.set(level.focus.apply_all(&mut character));
+
+        constitution.set(character.mechanical_properties.abilities.get(fage2e::Ability::Constitution).score);
+        willpower.set(character.mechanical_properties.abilities.get(fage2e::Ability::Willpower).score);
+
+        health_status.set(level.health.apply_all(&mut character));
+        if let Some(magic_points) = &level.magic_points {
+            magic_points_status.set(magic_points.apply_all(&mut character));
+        }
+
+        if let Some(defense) = &level.defense {
+            let _ = defense.apply_all(&mut character);
+        }
+        if let Some(talent) = &level.talent {
+            talent_status.set(talent.apply_all(&mut character));
+        }
+        if let Some(specialization) = &level.specialization {
+            specialization_status.set(specialization.apply_all(&mut character));
+        }
+        if let Some(stunt) = &level.stunt {
+            stunt_status.set(stunt.apply_all(&mut character));
+        }
+        if let Some(stunt) = &level.out_of_class_stunt {
+            out_of_class_stunt_status.set(stunt.apply_all(&mut character));
+        }
+        if let Some(spell) = &level.spell {
+            spell_status.set(spell.apply_all(&mut character));
+        }
+
+        info!("Updated level {} info", level.level);
+    });
+
+    use crate::advancement::DiceBasedHealthAdvancement;
+    use crate::advancement::MagicPointsAdvancement;
+    use crate::styling::{class_for_completeness, title_for_completeness};
+    use crate::widget::Selector;
+
+    let level_ref = level.read();
+    let level_ref = level_ref.deref();
+
+    rsx! {
+        h3 {
+            class: "title",
+            class: class_for_completeness(level_status()),
+            title: title_for_completeness(level_status()),
+            "Level {level_ref.level}"
+        }
+        div {
+            class: class_for_completeness(ability_status()),
+            title: title_for_completeness(ability_status()),
+            h4 {
+                class: "section-header",
+                match level_ref.ability {
+                    fage2e::AbilityAdvancement::Primary(_) => "Primary Ability Advancement",
+                    fage2e::AbilityAdvancement::Secondary(_) => "Secondary Ability Advancement",
+                }
+            }
+            Selector { options: ability_options, selection: ability }
+        }
+        div {
+            class: class_for_completeness(focus_status()),
+            title: title_for_completeness(focus_status()),
+            h4 {
+                class: "section-header",
+                match level_ref.focus {
+                    fage2e::FocusAdvancement::Primary(_) => "Primary Focus",
+                    fage2e::FocusAdvancement::Secondary(_) => "Secondary Focus",
+                }
+            }
+            Selector { options: focus_options, selection: focus }
+        }
+        hr {}
+        div {
+            class: class_for_completeness(health_status()),
+            title: title_for_completeness(health_status()),
+            match level_ref.health {
+                fage2e::HealthAdvancement::Dice(_) => rsx! {
+                    DiceBasedHealthAdvancement { advancement: health, constitution }
+                },
+                fage2e::HealthAdvancement::Constitution(_) => rsx! {
+                    h4 { class: "section-header", "Health Advancement" }
+                    p { class: "label", "CON ({constitution()}), minimum 1" }
+                },
+            }
+        }
+        if level_ref.magic_points.is_some() {
+            div {
+                class: class_for_completeness(magic_points_status()),
+                title: title_for_completeness(magic_points_status()),
+                MagicPointsAdvancement { advancement: magic_points, willpower }
+            }
+        }
+        if level_ref.defense.is_some() {
+            hr {}
+            h4 { class: "section-header", "Defense Advancement" }
+            p { class: "label", "+1 Defense" }
+        }
+        if level_ref.talent.is_some() {
+            hr {}
+            div {
+                class: class_for_completeness(talent_status()),
+                title: title_for_completeness(talent_status()),
+                h4 { class: "section-header", "Talent" }
+                p { class: "label", "Take a new talent or advance one you have." }
+                Selector { options: talent_options, selection: talent }
+            }
+        }
+        if level_ref.specialization.is_some() {
+            hr {}
+            div {
+                class: class_for_completeness(specialization_status()),
+                title: title_for_completeness(specialization_status()),
+                h4 { class: "section-header", "Specialization" }
+                p { class: "label", "Take a new specialization or advance the one you have." }
+                Selector { options: specialization_options, selection: specialization }
+                p { class: "label", "Or, take a talent instead:" }
+                Selector { options: talent_options, selection: specialization_talent }
+            }
+        }
+        if level_ref.stunt.is_some() {
+            hr {}
+            div {
+                class: class_for_completeness(stunt_status()),
+                title: title_for_completeness(stunt_status()),
+                h4 { class: "section-header", "Stunt" }
+                p { class: "label", "Learn a new stunt from your class." }
+                Selector { options: stunt_options, selection: stunt }
+            }
+        }
+        if level_ref.out_of_class_stunt.is_some() {
+            hr {}
+            div {
+                class: class_for_completeness(out_of_class_stunt_status()),
+                title: title_for_completeness(out_of_class_stunt_status()),
+                h4 { class: "section-header", "Out-of-Class Stunt" }
+                p { class: "label", "Learn a new stunt from any class." }
+                Selector { options: out_of_class_stunt_options, selection: out_of_class_stunt }
+            }
+        }
+        if level_ref.spell.is_some() {
+            hr {}
+            div {
+                class: class_for_completeness(spell_status()),
+                title: title_for_completeness(spell_status()),
+                h4 { class: "section-header", "Spell" }
+                p { class: "label", "Learn a new spell from an arcana you know." }
+                Selector { options: spell_options, selection: spell }
+            }
+        }
+    }
+}
@@ -5,9 +5,11 @@ use fage2e;
 mod advancement;
 mod character_sheet;
 mod customizer_bar;
+mod equipment;
 mod level;
 mod styling;
 mod util;
+mod validation;
 mod widget;
 
 #[allow(non_snake_case)]
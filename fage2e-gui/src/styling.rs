@@ -1,10 +1,19 @@
 
 /// Returns the CSS class to use for an advancement whose "completeness" status
 /// is given.
-pub fn class_for_completeness(completeness: Result<bool, ()>) -> &'static str {
+pub fn class_for_completeness(completeness: Result<bool, fage2e::AdvancementError>) -> &'static str {
     match completeness {
         Ok(true) => "",
         Ok(false) => "incomplete",
-        Err(()) => "error",
+        Err(_) => "error",
+    }
+}
+
+/// Returns the tooltip to use for an advancement whose "completeness" status
+/// is given, explaining what's wrong if there's an error.
+pub fn title_for_completeness(completeness: Result<bool, fage2e::AdvancementError>) -> String {
+    match completeness {
+        Ok(_) => String::new(),
+        Err(err) => err.to_string(),
     }
 }
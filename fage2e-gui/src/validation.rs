@@ -0,0 +1,39 @@
+use dioxus::prelude::*;
+
+use fage2e;
+
+/// Component listing every choice at a level that still needs the user's attention.
+///
+/// Args:
+/// * level: The level the report is for.
+/// * report: The validation report for that level.
+#[component]
+pub fn ValidationSummary(level: u8, report: ReadOnlySignal<fage2e::ValidationReport>) -> Element {
+    rsx! {
+        div {
+            class: "validation-summary",
+            h4 { class: "section-header", "Remaining Choices" }
+            if (*report.read()).is_complete() {
+                p { class: "label", "All choices for level {level} have been made." }
+            }
+            for issue in (*report.read()).errors() {
+                p {
+                    key: "{issue.path}",
+                    class: "label error",
+                    "{issue.path}: "
+                    match &issue.problem {
+                        fage2e::ValidationProblem::Invalid(err) => format!("{err}"),
+                        fage2e::ValidationProblem::Incomplete => String::new(),
+                    }
+                }
+            }
+            for issue in (*report.read()).incomplete() {
+                p {
+                    key: "{issue.path}",
+                    class: "label incomplete",
+                    "{issue.path}: not filled out yet"
+                }
+            }
+        }
+    }
+}
@@ -103,7 +103,16 @@ pub enum Focus {
     FightingSpears,
 
     // INTELLIGENCE
-    IntelligenceArcana,  // TODO: There are multiple variations on this, aren't there?
+    IntelligenceAirArcana,
+    IntelligenceDivinationArcana,
+    IntelligenceEarthArcana,
+    IntelligenceFireArcana,
+    IntelligenceHealingArcana,
+    IntelligenceHeroicArcana,
+    IntelligenceLightningArcana,
+    IntelligencePowerArcana,
+    IntelligenceShadowArcana,
+    IntelligenceWaterArcana,
     IntelligenceArcaneLore,
     IntelligenceBrewing,
     IntelligenceCartography,
@@ -204,7 +213,16 @@ impl Focus {
             Self::FightingSpears => Ability::Fighting,
 
             // INTELLIGENCE
-            Self::IntelligenceArcana |
+            Self::IntelligenceAirArcana |
+            Self::IntelligenceDivinationArcana |
+            Self::IntelligenceEarthArcana |
+            Self::IntelligenceFireArcana |
+            Self::IntelligenceHealingArcana |
+            Self::IntelligenceHeroicArcana |
+            Self::IntelligenceLightningArcana |
+            Self::IntelligencePowerArcana |
+            Self::IntelligenceShadowArcana |
+            Self::IntelligenceWaterArcana |
             Self::IntelligenceArcaneLore |
             Self::IntelligenceBrewing |
             Self::IntelligenceCartography |
@@ -305,7 +323,16 @@ impl Focus {
             Self::FightingSpears => "Spears",
 
             // INTELLIGENCE
-            Self::IntelligenceArcana => "Arcana",
+            Self::IntelligenceAirArcana => "Air Arcana",
+            Self::IntelligenceDivinationArcana => "Divination Arcana",
+            Self::IntelligenceEarthArcana => "Earth Arcana",
+            Self::IntelligenceFireArcana => "Fire Arcana",
+            Self::IntelligenceHealingArcana => "Healing Arcana",
+            Self::IntelligenceHeroicArcana => "Heroic Arcana",
+            Self::IntelligenceLightningArcana => "Lightning Arcana",
+            Self::IntelligencePowerArcana => "Power Arcana",
+            Self::IntelligenceShadowArcana => "Shadow Arcana",
+            Self::IntelligenceWaterArcana => "Water Arcana",
             Self::IntelligenceArcaneLore => "Arcane Lore",
             Self::IntelligenceBrewing => "Brewing",
             Self::IntelligenceCartography => "Cartography",
@@ -374,6 +401,16 @@ pub enum FocusLevel {
     DoubleFocus,
 }
 
+impl FocusLevel {
+    /// The bonus this focus level adds to tests.
+    pub fn bonus(&self) -> i8 {
+        match self {
+            Self::SingleFocus => 2,
+            Self::DoubleFocus => 3,
+        }
+    }
+}
+
 /// An individual ability score, keeping track of partial advancements.
 ///
 /// This class supports adding advancements directly using + or -, and this handles
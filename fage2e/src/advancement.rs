@@ -6,7 +6,8 @@ use std::cmp::max;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Ability, AdditiveModifier, Character, Dice, Focus, FocusLevel, ModifierSource};
+use crate::{Ability, AdditiveModifier, AdvancementError, Character, Class, Dice, Focus, FocusLevel, ModifierSource};
+use crate::{Specialization, SpecializationDetails, Spell, Stunt, StuntSource, Talent, TalentDegree};
 
 /// A generic character advancement.
 ///
@@ -24,8 +25,8 @@ pub trait Advancement {
     /// Return Value:
     /// * Ok(true) if the advancement was fully filled out and applied successfully.
     /// * Ok(false) if the advancement wasn't fully filled out, but there weren't other problems.
-    /// * Err() if there was an error and the advancement couldn't be applied.
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()>;
+    /// * Err(e) if there was an error and the advancement couldn't be applied.
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError>;
 
     /// Apply this advancement and all its sub-advancements (recursively) to the given character.
     ///
@@ -35,26 +36,29 @@ pub trait Advancement {
     /// Return Value:
     /// * Ok(true) if the advancement was fully filled out and applied successfully.
     /// * Ok(false) if the advancement wasn't fully filled out, but there weren't other problems.
-    /// * Err() if there was an error and the advancement couldn't be applied.
-    fn apply_all(&self, char: &mut Character) -> Result<bool, ()>
+    /// * Err(e) if there was an error and the advancement couldn't be applied.
+    fn apply_all(&self, char: &mut Character) -> Result<bool, AdvancementError>
     {
         // Start with self, aborting early if there was an error.
         let self_done = self.apply_self(char)?;
 
         // Iterate over every subadvancement and fully apply each until the first error.
-        let mut rollup: Result<bool, ()> = Ok(true);
-        self.foreach(&mut |adv: &dyn Advancement| {
-            rollup = rollup.and_then(|done| {
+        let mut rollup: Result<bool, AdvancementError> = Ok(true);
+        self.foreach(&mut |_, adv: &dyn Advancement| {
+            if let Ok(done) = rollup {
                 // Be sure to avoid short-circuiting issues.
-                Ok(adv.apply_all(char)? && done)
-            })
+                rollup = adv.apply_all(char).map(|adv_done| adv_done && done);
+            }
         });
 
         rollup.map(|rollup_done| rollup_done && self_done)
     }
 
     /// Apply the given function to each sub-advancement (immutable access).
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement));
+    ///
+    /// The function is also given the sub-advancement's name, which is generally the name
+    /// of the field holding it, or of the enum variant for wrapper advancements.
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement));
 
     /// Apply the given function to each sub-advancement (mutable access).
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement));
@@ -76,17 +80,17 @@ pub trait LeafNodeAdvancement {
     /// Return Value:
     /// * Ok(true) if the advancement was fully filled out and applied successfully.
     /// * Ok(false) if the advancement wasn't fully filled out, but there weren't other problems.
-    /// * Err() if there was an error and the advancement couldn't be applied.
-    fn apply(&self, char: &mut Character) -> Result<bool, ()>;
+    /// * Err(e) if there was an error and the advancement couldn't be applied.
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError>;
 }
 
 /// A LeafNodeAdvancement is, itself, an Advancement.
 impl<T: LeafNodeAdvancement + 'static> Advancement for T {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         self.apply(char)
     }
 
-    fn foreach(&self, _: &mut dyn FnMut(&dyn Advancement)) {
+    fn foreach(&self, _: &mut dyn FnMut(&str, &dyn Advancement)) {
     }
     fn foreach_mut(&mut self, _: &mut dyn FnMut(&mut dyn Advancement)) {
     }
@@ -101,7 +105,7 @@ pub struct DefenseAdvancement {
 }
 
 impl LeafNodeAdvancement for DefenseAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.defense_advancements.push(
             AdditiveModifier {
                 value: 1,
@@ -146,7 +150,7 @@ impl DiceBasedHealthAdvancement {
 }
 
 impl LeafNodeAdvancement for DiceBasedHealthAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let roll = match self.roll_result {
             None => return Ok(false),
             Some(v) => v as i16,
@@ -167,7 +171,66 @@ impl LeafNodeAdvancement for DiceBasedHealthAdvancement {
             Ok(true)
         }
         else {
-            Err(())
+            Err(AdvancementError::RollOutOfRange { roll, min: dice.min_value(), max: dice.max_value() })
+        }
+    }
+}
+
+/// An incremental advance on a mage's magic points, based on Willpower and a dice roll.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MagicPointsAdvancement {
+    /// The raw dice roll result, if it's been decided yet.
+    /// This does not include willpower modifiers.
+    pub roll_result: Option<u8>,
+}
+
+impl MagicPointsAdvancement {
+    /// The dice roll to use for this advancement.
+    pub fn dice() -> Dice {
+        Dice::d6(1)
+    }
+
+    /// The total advancement value, considering the minimum.
+    ///
+    /// NOTE: This does not check that the roll value itself was valid.
+    ///
+    /// Returns:
+    /// * Ok(val) if no coercion happened.
+    /// * Err(val) if the value was below the minimum advancement.
+    pub fn calculated(&self, willpower: i8) -> Result<i8, i8> {
+        let total = willpower + self.roll_result.unwrap_or(0) as i8;
+        if total < 1 {
+            Err(1)
+        } else {
+            Ok(total)
+        }
+    }
+}
+
+impl LeafNodeAdvancement for MagicPointsAdvancement {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let roll = match self.roll_result {
+            None => return Ok(false),
+            Some(v) => v as i16,
+        };
+        let dice = Self::dice();
+        if dice.min_value() <= roll && roll <= dice.max_value() {
+            let total = self.calculated(char.mechanical_properties.abilities.get(Ability::Willpower).score);
+            let total = match total {
+                Ok(v) => v,
+                Err(v) => v,
+            };
+            char.mechanical_properties.magic_point_advancements.push(
+                AdditiveModifier {
+                    value: total,
+                    source: ModifierSource::Level(char.mechanical_properties.level),
+                }
+            );
+            Ok(true)
+        }
+        else {
+            Err(AdvancementError::RollOutOfRange { roll, min: dice.min_value(), max: dice.max_value() })
         }
     }
 }
@@ -180,7 +243,7 @@ pub struct ConstitutionBasedHealthAdvancement {
 }
 
 impl LeafNodeAdvancement for ConstitutionBasedHealthAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let total = max(char.mechanical_properties.abilities.get(Ability::Constitution).score, 1);
         char.mechanical_properties.health_advancements.push(
             AdditiveModifier {
@@ -193,6 +256,53 @@ impl LeafNodeAdvancement for ConstitutionBasedHealthAdvancement {
 }
 
 
+/// A level's health advancement.
+///
+/// Through level 10, this includes a dice roll. From level 11 onward, only Constitution counts.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HealthAdvancement {
+    Dice(DiceBasedHealthAdvancement),
+    Constitution(ConstitutionBasedHealthAdvancement),
+}
+
+impl HealthAdvancement {
+    /// The health advancement for the given level.
+    pub fn for_level(level: u8) -> Self {
+        if level <= 10 {
+            Self::Dice(DiceBasedHealthAdvancement::default())
+        }
+        else {
+            Self::Constitution(ConstitutionBasedHealthAdvancement::default())
+        }
+    }
+}
+
+/// This is just a wrapper for the individual sub-advancements.
+impl Advancement for HealthAdvancement {
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
+        // Nothing to do here.
+        Ok(true)
+    }
+
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        match self {
+            Self::Dice(s) => f("dice", s),
+            Self::Constitution(s) => f("constitution", s),
+        }
+    }
+
+    fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
+        match self {
+            Self::Dice(s) => f(s),
+            Self::Constitution(s) => f(s),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+
 /// An incremental advancement on a single primary ability score.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -202,14 +312,14 @@ pub struct PrimaryAbilityAdvancement {
 }
 
 impl LeafNodeAdvancement for PrimaryAbilityAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let ability = match self.ability {
             None => return Ok(false),
             Some(a) => a,
         };
 
         let class = match char.mechanical_properties.class {
-            None => return Err(()),
+            None => return Err(AdvancementError::NoClass),
             Some(c) => c,
         };
 
@@ -218,7 +328,7 @@ impl LeafNodeAdvancement for PrimaryAbilityAdvancement {
             Ok(true)
         }
         else {
-            Err(())
+            Err(AdvancementError::AbilityNotPrimary { ability, class })
         }
     }
 }
@@ -232,14 +342,14 @@ pub struct SecondaryAbilityAdvancement {
 }
 
 impl LeafNodeAdvancement for SecondaryAbilityAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let ability = match self.ability {
             None => return Ok(false),
             Some(a) => a,
         };
 
         let class = match char.mechanical_properties.class {
-            None => return Err(()),
+            None => return Err(AdvancementError::NoClass),
             Some(c) => c,
         };
 
@@ -248,17 +358,87 @@ impl LeafNodeAdvancement for SecondaryAbilityAdvancement {
             Ok(true)
         }
         else {
-            Err(())
+            Err(AdvancementError::AbilityNotSecondary { ability, class })
+        }
+    }
+}
+
+/// A level's ability advancement.
+///
+/// Even levels advance a primary ability; odd levels advance a secondary ability.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AbilityAdvancement {
+    Primary(PrimaryAbilityAdvancement),
+    Secondary(SecondaryAbilityAdvancement),
+}
+
+impl AbilityAdvancement {
+    /// The ability advancement for the given level.
+    pub fn for_level(level: u8) -> Self {
+        if level.is_multiple_of(2) {
+            Self::Primary(PrimaryAbilityAdvancement::default())
+        }
+        else {
+            Self::Secondary(SecondaryAbilityAdvancement::default())
+        }
+    }
+
+    /// The abilities the given class may advance with this advancement.
+    pub fn options(&self, class: Class) -> &'static [Ability] {
+        match self {
+            Self::Primary(_) => class.primary_abilities(),
+            Self::Secondary(_) => class.secondary_abilities(),
+        }
+    }
+
+    /// Which ability to advance, or None if not selected yet.
+    pub fn ability(&self) -> Option<Ability> {
+        match self {
+            Self::Primary(s) => s.ability,
+            Self::Secondary(s) => s.ability,
+        }
+    }
+
+    /// Mutable access to which ability to advance.
+    pub fn ability_mut(&mut self) -> &mut Option<Ability> {
+        match self {
+            Self::Primary(s) => &mut s.ability,
+            Self::Secondary(s) => &mut s.ability,
         }
     }
 }
 
+/// This is just a wrapper for the individual sub-advancements.
+impl Advancement for AbilityAdvancement {
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
+        // Nothing to do here.
+        Ok(true)
+    }
+
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        match self {
+            Self::Primary(s) => f("primary", s),
+            Self::Secondary(s) => f("secondary", s),
+        }
+    }
 
-fn advance_focus(char: &mut Character, focus: Focus) -> Result<(), ()> {
+    fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
+        match self {
+            Self::Primary(s) => f(s),
+            Self::Secondary(s) => f(s),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+
+fn advance_focus(char: &mut Character, focus: Focus) -> Result<(), AdvancementError> {
     if let Some(&current_level) = char.mechanical_properties.focuses.get(&focus) {
         // Can't triple focus.
         if current_level == FocusLevel::DoubleFocus {
-            Err(())
+            Err(AdvancementError::FocusAlreadyDoubled(focus))
         }
         // You can double focus, but only starting at level 11.
         else if char.mechanical_properties.level >= 11 {
@@ -267,7 +447,7 @@ fn advance_focus(char: &mut Character, focus: Focus) -> Result<(), ()> {
         }
         // Otherwise you can't double focus yet.
         else {
-            Err(())
+            Err(AdvancementError::DoubleFocusBeforeLevel11(focus))
         }
     }
     else {
@@ -286,24 +466,22 @@ pub struct PrimaryFocusAdvancement {
 }
 
 impl LeafNodeAdvancement for PrimaryFocusAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self.focus {
             None => return Ok(false),
             Some(f) => f,
         };
 
         let class = match char.mechanical_properties.class {
-            None => return Err(()),
+            None => return Err(AdvancementError::NoClass),
             Some(c) => c,
         };
 
         if !class.primary_abilities().contains(&focus.ability()) {
-            return Err(());
-        }
-        match advance_focus(char, focus) {
-            Ok(()) => Ok(true),
-            Err(()) => Err(()),
+            return Err(AdvancementError::FocusNotPrimary { focus, class });
         }
+        advance_focus(char, focus)?;
+        Ok(true)
     }
 }
 
@@ -316,57 +494,281 @@ pub struct SecondaryFocusAdvancement {
 }
 
 impl LeafNodeAdvancement for SecondaryFocusAdvancement {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self.focus {
             None => return Ok(false),
             Some(f) => f,
         };
 
         let class = match char.mechanical_properties.class {
-            None => return Err(()),
+            None => return Err(AdvancementError::NoClass),
             Some(c) => c,
         };
 
         if !class.secondary_abilities().contains(&focus.ability()) {
-            return Err(());
+            return Err(AdvancementError::FocusNotSecondary { focus, class });
+        }
+        advance_focus(char, focus)?;
+        Ok(true)
+    }
+}
+
+/// A level's focus advancement.
+///
+/// Even levels take a focus in a primary ability; odd levels take one in a secondary ability.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FocusAdvancement {
+    Primary(PrimaryFocusAdvancement),
+    Secondary(SecondaryFocusAdvancement),
+}
+
+impl FocusAdvancement {
+    /// The focus advancement for the given level.
+    pub fn for_level(level: u8) -> Self {
+        if level.is_multiple_of(2) {
+            Self::Primary(PrimaryFocusAdvancement::default())
+        }
+        else {
+            Self::Secondary(SecondaryFocusAdvancement::default())
+        }
+    }
+
+    /// The abilities whose focuses the given class may take with this advancement.
+    pub fn ability_options(&self, class: Class) -> &'static [Ability] {
+        match self {
+            Self::Primary(_) => class.primary_abilities(),
+            Self::Secondary(_) => class.secondary_abilities(),
+        }
+    }
+
+    /// Which focus to take or advance, or None if not selected yet.
+    pub fn focus(&self) -> Option<Focus> {
+        match self {
+            Self::Primary(s) => s.focus,
+            Self::Secondary(s) => s.focus,
+        }
+    }
+
+    /// Mutable access to which focus to take or advance.
+    pub fn focus_mut(&mut self) -> &mut Option<Focus> {
+        match self {
+            Self::Primary(s) => &mut s.focus,
+            Self::Secondary(s) => &mut s.focus,
         }
-        match advance_focus(char, focus) {
-            Ok(()) => Ok(true),
-            Err(()) => Err(()),
+    }
+}
+
+/// This is just a wrapper for the individual sub-advancements.
+impl Advancement for FocusAdvancement {
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
+        // Nothing to do here.
+        Ok(true)
+    }
+
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        match self {
+            Self::Primary(s) => f("primary", s),
+            Self::Secondary(s) => f("secondary", s),
+        }
+    }
+
+    fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
+        match self {
+            Self::Primary(s) => f(s),
+            Self::Secondary(s) => f(s),
         }
     }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
 }
 
 
-/// An opportunity to pick a new stunt ability.
+/// An opportunity to learn a new spell from an arcana the character already knows.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpellAdvancement {
+    /// Which spell to learn.
+    pub spell: Option<Spell>,
+}
+
+impl LeafNodeAdvancement for SpellAdvancement {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let spell = match self.spell {
+            None => return Ok(false),
+            Some(s) => s,
+        };
+        if !char.mechanical_properties.arcana.contains(&spell.arcana()) {
+            return Err(AdvancementError::ArcanaNotKnown { spell, arcana: spell.arcana() });
+        }
+        if char.mechanical_properties.level < spell.min_level() {
+            return Err(AdvancementError::SpellAboveLevel { spell, min_level: spell.min_level() });
+        }
+        if !char.mechanical_properties.spells.insert(spell) {
+            return Err(AdvancementError::SpellAlreadyKnown(spell));
+        }
+        Ok(true)
+    }
+}
+
+
+pub(crate) fn learn_stunt(char: &mut Character, stunt: Stunt, source: StuntSource) -> Result<(), AdvancementError> {
+    // Ancestry stunts only come from ancestry benefits.
+    if stunt.ancestry().is_some() {
+        return Err(AdvancementError::AncestryStunt(stunt));
+    }
+    if char.mechanical_properties.stunts.contains_key(&stunt) {
+        return Err(AdvancementError::StuntAlreadyKnown(stunt));
+    }
+    char.mechanical_properties.stunts.insert(stunt, source);
+    Ok(())
+}
+
+/// An opportunity to pick a new stunt ability from your class's stunts.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StuntAdvancement {
-    // TODO: Option<Stunt>
+    /// Which stunt to learn.
+    pub stunt: Option<Stunt>,
+}
+
+impl LeafNodeAdvancement for StuntAdvancement {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let stunt = match self.stunt {
+            None => return Ok(false),
+            Some(s) => s,
+        };
+        let class = match char.mechanical_properties.class {
+            None => return Err(AdvancementError::NoClass),
+            Some(c) => c,
+        };
+        if !stunt.classes().contains(&class) {
+            return Err(AdvancementError::StuntNotInClass { stunt, class });
+        }
+        learn_stunt(char, stunt, StuntSource::Class)?;
+        Ok(true)
+    }
 }
 
 /// An opportunity to pick a new out-of-class (or in-class) stunt ability.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OutOfClassStuntAdvancement {
-    // TODO: Option<Stunt>
+    /// Which stunt to learn.
+    pub stunt: Option<Stunt>,
+}
+
+impl LeafNodeAdvancement for OutOfClassStuntAdvancement {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let stunt = match self.stunt {
+            None => return Ok(false),
+            Some(s) => s,
+        };
+        learn_stunt(char, stunt, StuntSource::OutOfClass)?;
+        Ok(true)
+    }
 }
 
 
+/// Grants one of a class's starting talents at Novice degree.
+///
+/// Starting talents don't have to meet the talent's usual requirements, but they do have
+/// to be one of the class's options.
+pub(crate) fn grant_starting_talent(char: &mut Character, talent: Talent, options: &[Talent]) -> Result<(), AdvancementError> {
+    if !options.contains(&talent) {
+        return Err(AdvancementError::TalentNotAvailable(talent));
+    }
+    char.mechanical_properties.talents.entry(talent).or_insert(TalentDegree::Novice);
+    Ok(())
+}
+
+fn advance_talent(char: &mut Character, talent: Talent) -> Result<(), AdvancementError> {
+    if !talent.is_available_to(char) {
+        return Err(AdvancementError::TalentNotAvailable(talent));
+    }
+    let degree = match char.mechanical_properties.talents.get(&talent) {
+        None => TalentDegree::Novice,
+        // Can't advance past Master.
+        Some(degree) => degree.next().ok_or(AdvancementError::TalentAlreadyMastered(talent))?,
+    };
+    char.mechanical_properties.talents.insert(talent, degree);
+    Ok(())
+}
+
 /// An opportunity to pick a new talent or advance an already-taken one.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TalentAdvancement {
-    // TODO: Option<Talent>
+    /// Which talent to take or advance.
+    pub talent: Option<Talent>,
+}
+
+impl LeafNodeAdvancement for TalentAdvancement {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let talent = match self.talent {
+            None => return Ok(false),
+            Some(t) => t,
+        };
+        advance_talent(char, talent)?;
+        Ok(true)
+    }
 }
 
 
+fn advance_specialization(char: &mut Character, specialization: Specialization) -> Result<(), AdvancementError> {
+    // Specializations are class-specific.
+    let class = char.mechanical_properties.class;
+    if class != Some(specialization.class()) {
+        return Err(AdvancementError::SpecializationWrongClass { specialization, class });
+    }
+    if !specialization.requirements().iter().all(|req| req.is_met(char)) {
+        return Err(AdvancementError::SpecializationRequirementsNotMet(specialization));
+    }
+
+    let specializations = &mut char.mechanical_properties.powers.specializations;
+    if let Some(details) = specializations.iter_mut().find(|d| d.specialization == specialization) {
+        // Can't advance past Master.
+        details.degree = details.degree.next()
+            .ok_or(AdvancementError::SpecializationAlreadyMastered(specialization))?;
+    }
+    else {
+        // You have to master your current specialization before starting another.
+        if let Some(current) = specializations.iter().find(|d| d.degree != TalentDegree::Master) {
+            return Err(AdvancementError::SpecializationNotMastered(current.specialization));
+        }
+        specializations.push(SpecializationDetails { specialization, degree: TalentDegree::Novice });
+    }
+    Ok(())
+}
+
 /// An opportunity to pick a new specialization or advance an already-taken one.
 ///
-/// Alternatively, you can take a regular talent, with some restrictions.
+/// Alternatively, you can take a regular talent instead, subject to that talent's usual
+/// class restrictions and requirements. Only one of the two may be chosen.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpecializationAdvancement {
-    // TODO: Option<Specialization>
-    // TODO: Option<Talent>
+    /// Which specialization to take or advance.
+    pub specialization: Option<Specialization>,
+
+    /// Which talent to take or advance instead of a specialization.
+    pub talent: Option<Talent>,
+}
+
+impl LeafNodeAdvancement for SpecializationAdvancement {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        match (self.specialization, self.talent) {
+            (None, None) => Ok(false),
+            (Some(specialization), None) => {
+                advance_specialization(char, specialization)?;
+                Ok(true)
+            },
+            (None, Some(talent)) => {
+                advance_talent(char, talent)?;
+                Ok(true)
+            },
+            // It's one or the other, not both.
+            (Some(_), Some(_)) => Err(AdvancementError::SpecializationAndTalent),
+        }
+    }
 }
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{into_generic_power_option, Ability, Advancement, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, Ancestry, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics, Stunt, StuntSource};
 
 // -----------------------------------------------------------------------------
 // LEVEL 1 SELECTIONS
@@ -24,14 +24,14 @@ pub struct Level1Selections {
 /// such as Dark Sight and available languages. Sub-advancements cover the user's
 /// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.powers.dark_sight = Some(DarkSightDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
-        f(&self.benefits);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
@@ -61,7 +61,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Intimidation) => Focus::StrengthIntimidation,
@@ -176,7 +176,7 @@ impl AncestryBenefit for DraakBenefit {
                 true
             },
             Self::FlameBreath => {
-                // TODO
+                char.mechanical_properties.stunts.insert(Stunt::FlameBreath, StuntSource::Ancestry(Ancestry::Draak));
                 true
             },
             Self::IntelligenceResearch => {
@@ -232,7 +232,7 @@ impl DraakPowerDetails {
             into_generic_power_option(&self.magical_resistance),
         ]
             .into_iter()
-            .filter_map(|opt| opt)
+            .flatten()
     }
 
     /// Look up a power by ID.
@@ -289,6 +289,10 @@ impl PowerMechanics for MagicalResistanceDetails {
     fn description(&self) -> String {
         format!("Use {} to resist or reduce the effects of a spell.", self.focus_choice.focus())
     }
+
+    fn magical_resistance_focus(&self) -> Option<Focus> {
+        Some(self.focus_choice.focus())
+    }
 }
 
 /// Which focus the player chose for Magical Resistance.
@@ -310,3 +314,29 @@ impl MagicalResistanceFocusChoice {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, DraakBenefit::PlusOneConstitution),
+            (3, DraakBenefit::Armored), (4, DraakBenefit::Armored),
+            (5, DraakBenefit::ConstitutionStamina),
+            (6, DraakBenefit::MagicalResistance(None)),
+            (7, DraakBenefit::PlusOneIntelligence), (8, DraakBenefit::PlusOneIntelligence),
+            (9, DraakBenefit::FlameBreath),
+            (10, DraakBenefit::IntelligenceResearch),
+            (11, DraakBenefit::PlusOneWillpower),
+            (12, DraakBenefit::PlusOneStrength),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(DraakBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(DraakBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,24 +13,27 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Dwarf benefits
+    pub benefits: AncestryBenefitSelections<DwarfBenefit>,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as Dark Sight and available languages. Sub-advancements cover the user's
 /// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.powers.dark_sight = Some(DarkSightDetails {});
+        char.mechanical_properties.powers.dwarf.stout = Some(StoutDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -55,7 +58,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Tolerance) => Focus::ConstitutionTolerance,
@@ -70,3 +73,207 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The Dwarf benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DwarfBenefit {
+    PlusOneConstitution,
+    FightingBludgeons,
+    StrengthSmithing,
+    IntelligenceEngineering,
+    PlusOneStrength,
+    FightingAxes,
+    PerceptionSearching,
+    PlusOneWillpower,
+    PlusOneFighting,
+}
+
+impl std::fmt::Display for DwarfBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for DwarfBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOneConstitution => "+1 Constitution",
+            Self::FightingBludgeons => "Fighting (Bludgeons)",
+            Self::StrengthSmithing => "Strength (Smithing)",
+            Self::IntelligenceEngineering => "Intelligence (Engineering)",
+            Self::PlusOneStrength => "+1 Strength",
+            Self::FightingAxes => "Fighting (Axes)",
+            Self::PerceptionSearching => "Perception (Searching)",
+            Self::PlusOneWillpower => "+1 Willpower",
+            Self::PlusOneFighting => "+1 Fighting",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOneConstitution,
+            3..=4 => Self::FightingBludgeons,
+            5 => Self::StrengthSmithing,
+            6 => Self::IntelligenceEngineering,
+            7..=8 => Self::PlusOneStrength,
+            9 => Self::FightingAxes,
+            10 => Self::PerceptionSearching,
+            11 => Self::PlusOneWillpower,
+            12 => Self::PlusOneFighting,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOneConstitution => true,
+            Self::FightingBludgeons => false,
+            Self::StrengthSmithing => false,
+            Self::IntelligenceEngineering => false,
+            Self::PlusOneStrength => true,
+            Self::FightingAxes => false,
+            Self::PerceptionSearching => false,
+            Self::PlusOneWillpower => true,
+            Self::PlusOneFighting => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOneConstitution => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Constitution) += 1;
+                true
+            },
+            Self::FightingBludgeons => {
+                char.mechanical_properties.focuses.insert(Focus::FightingBludgeons, FocusLevel::SingleFocus);
+                true
+            },
+            Self::StrengthSmithing => {
+                char.mechanical_properties.focuses.insert(Focus::StrengthSmithing, FocusLevel::SingleFocus);
+                true
+            },
+            Self::IntelligenceEngineering => {
+                char.mechanical_properties.focuses.insert(Focus::IntelligenceEngineering, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneStrength => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Strength) += 1;
+                true
+            },
+            Self::FightingAxes => {
+                char.mechanical_properties.focuses.insert(Focus::FightingAxes, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PerceptionSearching => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionSearching, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneWillpower => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Willpower) += 1;
+                true
+            },
+            Self::PlusOneFighting => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Fighting) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Dwarf-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DwarfPower {
+    Stout,
+}
+
+impl std::fmt::Display for DwarfPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stout => write!(f, "Stout (Dwarf)"),
+        }
+    }
+}
+
+/// Details about the various Dwarf powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DwarfPowerDetails {
+    pub stout: Option<StoutDetails>,
+}
+
+impl DwarfPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.stout),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: DwarfPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            DwarfPower::Stout => into_generic_power_option(&self.stout),
+        }
+    }
+}
+
+/// Metadata about the Stout power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StoutDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for StoutDetails {
+    fn power(&self) -> crate::Power {
+        DwarfPower::Stout.into()
+    }
+
+    fn name(&self) -> String {
+        "Stout (Dwarf)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "+2 on tests to resist poison and to avoid being knocked prone.".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, DwarfBenefit::PlusOneConstitution),
+            (3, DwarfBenefit::FightingBludgeons), (4, DwarfBenefit::FightingBludgeons),
+            (5, DwarfBenefit::StrengthSmithing),
+            (6, DwarfBenefit::IntelligenceEngineering),
+            (7, DwarfBenefit::PlusOneStrength), (8, DwarfBenefit::PlusOneStrength),
+            (9, DwarfBenefit::FightingAxes),
+            (10, DwarfBenefit::PerceptionSearching),
+            (11, DwarfBenefit::PlusOneWillpower),
+            (12, DwarfBenefit::PlusOneFighting),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(DwarfBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(DwarfBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,24 +13,26 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Elf benefits
+    pub benefits: AncestryBenefitSelections<ElfBenefit>,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as Dark Sight and available languages. Sub-advancements cover the user's
 /// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.powers.dark_sight = Some(DarkSightDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -55,7 +57,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::NaturalLore) => Focus::IntelligenceNaturalLore,
@@ -70,3 +72,142 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The Elf benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ElfBenefit {
+    PlusOnePerception,
+    AccuracyBows,
+    IntelligenceArcaneLore,
+    DexterityStealth,
+    PlusOneDexterity,
+    PerceptionHearing,
+    CommunicationPerformance,
+    PlusOneIntelligence,
+    PlusOneAccuracy,
+}
+
+impl std::fmt::Display for ElfBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for ElfBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOnePerception => "+1 Perception",
+            Self::AccuracyBows => "Accuracy (Bows)",
+            Self::IntelligenceArcaneLore => "Intelligence (Arcane Lore)",
+            Self::DexterityStealth => "Dexterity (Stealth)",
+            Self::PlusOneDexterity => "+1 Dexterity",
+            Self::PerceptionHearing => "Perception (Hearing)",
+            Self::CommunicationPerformance => "Communication (Performance)",
+            Self::PlusOneIntelligence => "+1 Intelligence",
+            Self::PlusOneAccuracy => "+1 Accuracy",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOnePerception,
+            3..=4 => Self::AccuracyBows,
+            5 => Self::IntelligenceArcaneLore,
+            6 => Self::DexterityStealth,
+            7..=8 => Self::PlusOneDexterity,
+            9 => Self::PerceptionHearing,
+            10 => Self::CommunicationPerformance,
+            11 => Self::PlusOneIntelligence,
+            12 => Self::PlusOneAccuracy,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOnePerception => true,
+            Self::AccuracyBows => false,
+            Self::IntelligenceArcaneLore => false,
+            Self::DexterityStealth => false,
+            Self::PlusOneDexterity => true,
+            Self::PerceptionHearing => false,
+            Self::CommunicationPerformance => false,
+            Self::PlusOneIntelligence => true,
+            Self::PlusOneAccuracy => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOnePerception => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Perception) += 1;
+                true
+            },
+            Self::AccuracyBows => {
+                char.mechanical_properties.focuses.insert(Focus::AccuracyBows, FocusLevel::SingleFocus);
+                true
+            },
+            Self::IntelligenceArcaneLore => {
+                char.mechanical_properties.focuses.insert(Focus::IntelligenceArcaneLore, FocusLevel::SingleFocus);
+                true
+            },
+            Self::DexterityStealth => {
+                char.mechanical_properties.focuses.insert(Focus::DexterityStealth, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneDexterity => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Dexterity) += 1;
+                true
+            },
+            Self::PerceptionHearing => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionHearing, FocusLevel::SingleFocus);
+                true
+            },
+            Self::CommunicationPerformance => {
+                char.mechanical_properties.focuses.insert(Focus::CommunicationPerformance, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneIntelligence => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Intelligence) += 1;
+                true
+            },
+            Self::PlusOneAccuracy => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Accuracy) += 1;
+                true
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, ElfBenefit::PlusOnePerception),
+            (3, ElfBenefit::AccuracyBows), (4, ElfBenefit::AccuracyBows),
+            (5, ElfBenefit::IntelligenceArcaneLore),
+            (6, ElfBenefit::DexterityStealth),
+            (7, ElfBenefit::PlusOneDexterity), (8, ElfBenefit::PlusOneDexterity),
+            (9, ElfBenefit::PerceptionHearing),
+            (10, ElfBenefit::CommunicationPerformance),
+            (11, ElfBenefit::PlusOneIntelligence),
+            (12, ElfBenefit::PlusOneAccuracy),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(ElfBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(ElfBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
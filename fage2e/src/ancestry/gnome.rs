@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,24 +13,27 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Gnome benefits
+    pub benefits: AncestryBenefitSelections<GnomeBenefit>,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as Dark Sight and available languages. Sub-advancements cover the user's
 /// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.powers.dark_sight = Some(DarkSightDetails {});
+        char.mechanical_properties.powers.gnome.animal_speech = Some(AnimalSpeechDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -55,7 +58,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::ArcaneBlast) => Focus::AccuracyArcaneBlast,
@@ -70,3 +73,207 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The Gnome benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GnomeBenefit {
+    PlusOneIntelligence,
+    CommunicationAnimalHandling,
+    IntelligenceEngineering,
+    DexterityCrafting,
+    PlusOneDexterity,
+    CommunicationDeception,
+    PerceptionHearing,
+    PlusOnePerception,
+    PlusOneWillpower,
+}
+
+impl std::fmt::Display for GnomeBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for GnomeBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOneIntelligence => "+1 Intelligence",
+            Self::CommunicationAnimalHandling => "Communication (Animal Handling)",
+            Self::IntelligenceEngineering => "Intelligence (Engineering)",
+            Self::DexterityCrafting => "Dexterity (Crafting)",
+            Self::PlusOneDexterity => "+1 Dexterity",
+            Self::CommunicationDeception => "Communication (Deception)",
+            Self::PerceptionHearing => "Perception (Hearing)",
+            Self::PlusOnePerception => "+1 Perception",
+            Self::PlusOneWillpower => "+1 Willpower",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOneIntelligence,
+            3..=4 => Self::CommunicationAnimalHandling,
+            5 => Self::IntelligenceEngineering,
+            6 => Self::DexterityCrafting,
+            7..=8 => Self::PlusOneDexterity,
+            9 => Self::CommunicationDeception,
+            10 => Self::PerceptionHearing,
+            11 => Self::PlusOnePerception,
+            12 => Self::PlusOneWillpower,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOneIntelligence => true,
+            Self::CommunicationAnimalHandling => false,
+            Self::IntelligenceEngineering => false,
+            Self::DexterityCrafting => false,
+            Self::PlusOneDexterity => true,
+            Self::CommunicationDeception => false,
+            Self::PerceptionHearing => false,
+            Self::PlusOnePerception => true,
+            Self::PlusOneWillpower => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOneIntelligence => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Intelligence) += 1;
+                true
+            },
+            Self::CommunicationAnimalHandling => {
+                char.mechanical_properties.focuses.insert(Focus::CommunicationAnimalHandling, FocusLevel::SingleFocus);
+                true
+            },
+            Self::IntelligenceEngineering => {
+                char.mechanical_properties.focuses.insert(Focus::IntelligenceEngineering, FocusLevel::SingleFocus);
+                true
+            },
+            Self::DexterityCrafting => {
+                char.mechanical_properties.focuses.insert(Focus::DexterityCrafting, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneDexterity => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Dexterity) += 1;
+                true
+            },
+            Self::CommunicationDeception => {
+                char.mechanical_properties.focuses.insert(Focus::CommunicationDeception, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PerceptionHearing => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionHearing, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOnePerception => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Perception) += 1;
+                true
+            },
+            Self::PlusOneWillpower => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Willpower) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Gnome-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GnomePower {
+    AnimalSpeech,
+}
+
+impl std::fmt::Display for GnomePower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AnimalSpeech => write!(f, "Animal Speech (Gnome)"),
+        }
+    }
+}
+
+/// Details about the various Gnome powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GnomePowerDetails {
+    pub animal_speech: Option<AnimalSpeechDetails>,
+}
+
+impl GnomePowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.animal_speech),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: GnomePower) -> Option<&dyn PowerMechanics> {
+        match power {
+            GnomePower::AnimalSpeech => into_generic_power_option(&self.animal_speech),
+        }
+    }
+}
+
+/// Metadata about the Animal Speech power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnimalSpeechDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for AnimalSpeechDetails {
+    fn power(&self) -> crate::Power {
+        GnomePower::AnimalSpeech.into()
+    }
+
+    fn name(&self) -> String {
+        "Animal Speech (Gnome)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "You can speak with burrowing animals, such as badgers and moles.".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, GnomeBenefit::PlusOneIntelligence),
+            (3, GnomeBenefit::CommunicationAnimalHandling), (4, GnomeBenefit::CommunicationAnimalHandling),
+            (5, GnomeBenefit::IntelligenceEngineering),
+            (6, GnomeBenefit::DexterityCrafting),
+            (7, GnomeBenefit::PlusOneDexterity), (8, GnomeBenefit::PlusOneDexterity),
+            (9, GnomeBenefit::CommunicationDeception),
+            (10, GnomeBenefit::PerceptionHearing),
+            (11, GnomeBenefit::PlusOnePerception),
+            (12, GnomeBenefit::PlusOneWillpower),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(GnomeBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(GnomeBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,24 +13,27 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Goblin benefits
+    pub benefits: AncestryBenefitSelections<GoblinBenefit>,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as Dark Sight and available languages. Sub-advancements cover the user's
 /// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.powers.dark_sight = Some(DarkSightDetails {});
+        char.mechanical_properties.powers.goblin.swift = Some(SwiftDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -55,7 +58,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Investigation) => Focus::CommunicationInvestigation,
@@ -70,3 +73,211 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The Goblin benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GoblinBenefit {
+    PlusOneDexterity,
+    ConstitutionRunning,
+    DexterityLegerdemain,
+    PerceptionSearching,
+    PlusOnePerception,
+    DexterityTraps,
+    CommunicationDeception,
+    PlusOneAccuracy,
+    PlusOneCommunication,
+}
+
+impl std::fmt::Display for GoblinBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for GoblinBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOneDexterity => "+1 Dexterity",
+            Self::ConstitutionRunning => "Constitution (Running)",
+            Self::DexterityLegerdemain => "Dexterity (Legerdemain)",
+            Self::PerceptionSearching => "Perception (Searching)",
+            Self::PlusOnePerception => "+1 Perception",
+            Self::DexterityTraps => "Dexterity (Traps)",
+            Self::CommunicationDeception => "Communication (Deception)",
+            Self::PlusOneAccuracy => "+1 Accuracy",
+            Self::PlusOneCommunication => "+1 Communication",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOneDexterity,
+            3..=4 => Self::ConstitutionRunning,
+            5 => Self::DexterityLegerdemain,
+            6 => Self::PerceptionSearching,
+            7..=8 => Self::PlusOnePerception,
+            9 => Self::DexterityTraps,
+            10 => Self::CommunicationDeception,
+            11 => Self::PlusOneAccuracy,
+            12 => Self::PlusOneCommunication,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOneDexterity => true,
+            Self::ConstitutionRunning => false,
+            Self::DexterityLegerdemain => false,
+            Self::PerceptionSearching => false,
+            Self::PlusOnePerception => true,
+            Self::DexterityTraps => false,
+            Self::CommunicationDeception => false,
+            Self::PlusOneAccuracy => true,
+            Self::PlusOneCommunication => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOneDexterity => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Dexterity) += 1;
+                true
+            },
+            Self::ConstitutionRunning => {
+                char.mechanical_properties.focuses.insert(Focus::ConstitutionRunning, FocusLevel::SingleFocus);
+                true
+            },
+            Self::DexterityLegerdemain => {
+                char.mechanical_properties.focuses.insert(Focus::DexterityLegerdemain, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PerceptionSearching => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionSearching, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOnePerception => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Perception) += 1;
+                true
+            },
+            Self::DexterityTraps => {
+                char.mechanical_properties.focuses.insert(Focus::DexterityTraps, FocusLevel::SingleFocus);
+                true
+            },
+            Self::CommunicationDeception => {
+                char.mechanical_properties.focuses.insert(Focus::CommunicationDeception, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneAccuracy => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Accuracy) += 1;
+                true
+            },
+            Self::PlusOneCommunication => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Communication) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Goblin-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GoblinPower {
+    Swift,
+}
+
+impl std::fmt::Display for GoblinPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Swift => write!(f, "Swift (Goblin)"),
+        }
+    }
+}
+
+/// Details about the various Goblin powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GoblinPowerDetails {
+    pub swift: Option<SwiftDetails>,
+}
+
+impl GoblinPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.swift),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: GoblinPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            GoblinPower::Swift => into_generic_power_option(&self.swift),
+        }
+    }
+}
+
+/// Metadata about the Swift power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SwiftDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for SwiftDetails {
+    fn power(&self) -> crate::Power {
+        GoblinPower::Swift.into()
+    }
+
+    fn name(&self) -> String {
+        "Swift (Goblin)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "+2 Speed".to_owned()
+    }
+
+    fn speed_bonus(&self) -> Option<i8> {
+        Some(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, GoblinBenefit::PlusOneDexterity),
+            (3, GoblinBenefit::ConstitutionRunning), (4, GoblinBenefit::ConstitutionRunning),
+            (5, GoblinBenefit::DexterityLegerdemain),
+            (6, GoblinBenefit::PerceptionSearching),
+            (7, GoblinBenefit::PlusOnePerception), (8, GoblinBenefit::PlusOnePerception),
+            (9, GoblinBenefit::DexterityTraps),
+            (10, GoblinBenefit::CommunicationDeception),
+            (11, GoblinBenefit::PlusOneAccuracy),
+            (12, GoblinBenefit::PlusOneCommunication),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(GoblinBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(GoblinBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
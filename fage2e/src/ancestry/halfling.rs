@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,22 +13,25 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Goblin benefits
+    pub benefits: AncestryBenefitSelections<HalflingBenefit>,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as available languages. Sub-advancements cover the user's choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        char.mechanical_properties.powers.halfling.steady = Some(SteadyDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -53,7 +56,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Bargaining) => Focus::CommunicationBargaining,
@@ -68,3 +71,207 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The Halfling benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HalflingBenefit {
+    PlusOnePerception,
+    WillpowerCourage,
+    DexterityLegerdemain,
+    PerceptionHearing,
+    PlusOneDexterity,
+    AccuracySlings,
+    CommunicationPersuasion,
+    PlusOneCommunication,
+    PlusOneWillpower,
+}
+
+impl std::fmt::Display for HalflingBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for HalflingBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOnePerception => "+1 Perception",
+            Self::WillpowerCourage => "Willpower (Courage)",
+            Self::DexterityLegerdemain => "Dexterity (Legerdemain)",
+            Self::PerceptionHearing => "Perception (Hearing)",
+            Self::PlusOneDexterity => "+1 Dexterity",
+            Self::AccuracySlings => "Accuracy (Slings)",
+            Self::CommunicationPersuasion => "Communication (Persuasion)",
+            Self::PlusOneCommunication => "+1 Communication",
+            Self::PlusOneWillpower => "+1 Willpower",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOnePerception,
+            3..=4 => Self::WillpowerCourage,
+            5 => Self::DexterityLegerdemain,
+            6 => Self::PerceptionHearing,
+            7..=8 => Self::PlusOneDexterity,
+            9 => Self::AccuracySlings,
+            10 => Self::CommunicationPersuasion,
+            11 => Self::PlusOneCommunication,
+            12 => Self::PlusOneWillpower,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOnePerception => true,
+            Self::WillpowerCourage => false,
+            Self::DexterityLegerdemain => false,
+            Self::PerceptionHearing => false,
+            Self::PlusOneDexterity => true,
+            Self::AccuracySlings => false,
+            Self::CommunicationPersuasion => false,
+            Self::PlusOneCommunication => true,
+            Self::PlusOneWillpower => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOnePerception => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Perception) += 1;
+                true
+            },
+            Self::WillpowerCourage => {
+                char.mechanical_properties.focuses.insert(Focus::WillpowerCourage, FocusLevel::SingleFocus);
+                true
+            },
+            Self::DexterityLegerdemain => {
+                char.mechanical_properties.focuses.insert(Focus::DexterityLegerdemain, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PerceptionHearing => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionHearing, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneDexterity => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Dexterity) += 1;
+                true
+            },
+            Self::AccuracySlings => {
+                char.mechanical_properties.focuses.insert(Focus::AccuracySlings, FocusLevel::SingleFocus);
+                true
+            },
+            Self::CommunicationPersuasion => {
+                char.mechanical_properties.focuses.insert(Focus::CommunicationPersuasion, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneCommunication => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Communication) += 1;
+                true
+            },
+            Self::PlusOneWillpower => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Willpower) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Halfling-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HalflingPower {
+    Steady,
+}
+
+impl std::fmt::Display for HalflingPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Steady => write!(f, "Steady (Halfling)"),
+        }
+    }
+}
+
+/// Details about the various Halfling powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HalflingPowerDetails {
+    pub steady: Option<SteadyDetails>,
+}
+
+impl HalflingPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.steady),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: HalflingPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            HalflingPower::Steady => into_generic_power_option(&self.steady),
+        }
+    }
+}
+
+/// Metadata about the Steady power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SteadyDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for SteadyDetails {
+    fn power(&self) -> crate::Power {
+        HalflingPower::Steady.into()
+    }
+
+    fn name(&self) -> String {
+        "Steady (Halfling)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "Once per encounter, re-roll a failed test to resist being frightened or knocked prone.".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, HalflingBenefit::PlusOnePerception),
+            (3, HalflingBenefit::WillpowerCourage), (4, HalflingBenefit::WillpowerCourage),
+            (5, HalflingBenefit::DexterityLegerdemain),
+            (6, HalflingBenefit::PerceptionHearing),
+            (7, HalflingBenefit::PlusOneDexterity), (8, HalflingBenefit::PlusOneDexterity),
+            (9, HalflingBenefit::AccuracySlings),
+            (10, HalflingBenefit::CommunicationPersuasion),
+            (11, HalflingBenefit::PlusOneCommunication),
+            (12, HalflingBenefit::PlusOneWillpower),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(HalflingBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(HalflingBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,22 +13,29 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Human benefits
+    pub benefits: AncestryBenefitSelections<HumanBenefit>,
+
+    /// The focus chosen for the Adaptable Focus power.
+    pub adaptable_focus: AdaptableFocusSelection,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as available languages. Sub-advancements cover the user's choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
+        f("adaptable_focus", &self.adaptable_focus);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
+        f(&mut self.adaptable_focus);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -53,7 +60,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Riding) => Focus::DexterityRiding,
@@ -68,3 +75,233 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The focus chosen for the Adaptable Focus power.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdaptableFocusSelection {
+    pub focus: Option<Focus>,
+}
+
+/// Grants the Adaptable Focus power with the chosen focus.
+impl LeafNodeAdvancement for AdaptableFocusSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let focus = match self.focus {
+            None => return Ok(false),
+            Some(focus) => focus,
+        };
+        if char.mechanical_properties.focuses.contains_key(&focus) {
+            Err(AdvancementError::FocusAlreadyKnown(focus))
+        }
+        else {
+            char.mechanical_properties.focuses.insert(focus, FocusLevel::SingleFocus);
+            char.mechanical_properties.powers.human.adaptable_focus = Some(AdaptableFocusDetails { focus });
+            Ok(true)
+        }
+    }
+}
+
+/// The Human benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HumanBenefit {
+    PlusOneStrength,
+    StrengthMight,
+    FightingHeavyBlades,
+    CommunicationPersuasion,
+    PlusOneConstitution,
+    StrengthClimbing,
+    IntelligenceCulturalLore,
+    PlusOneCommunication,
+    PlusOneDexterity,
+}
+
+impl std::fmt::Display for HumanBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for HumanBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOneStrength => "+1 Strength",
+            Self::StrengthMight => "Strength (Might)",
+            Self::FightingHeavyBlades => "Fighting (Heavy Blades)",
+            Self::CommunicationPersuasion => "Communication (Persuasion)",
+            Self::PlusOneConstitution => "+1 Constitution",
+            Self::StrengthClimbing => "Strength (Climbing)",
+            Self::IntelligenceCulturalLore => "Intelligence (Cultural Lore)",
+            Self::PlusOneCommunication => "+1 Communication",
+            Self::PlusOneDexterity => "+1 Dexterity",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOneStrength,
+            3..=4 => Self::StrengthMight,
+            5 => Self::FightingHeavyBlades,
+            6 => Self::CommunicationPersuasion,
+            7..=8 => Self::PlusOneConstitution,
+            9 => Self::StrengthClimbing,
+            10 => Self::IntelligenceCulturalLore,
+            11 => Self::PlusOneCommunication,
+            12 => Self::PlusOneDexterity,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOneStrength => true,
+            Self::StrengthMight => false,
+            Self::FightingHeavyBlades => false,
+            Self::CommunicationPersuasion => false,
+            Self::PlusOneConstitution => true,
+            Self::StrengthClimbing => false,
+            Self::IntelligenceCulturalLore => false,
+            Self::PlusOneCommunication => true,
+            Self::PlusOneDexterity => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOneStrength => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Strength) += 1;
+                true
+            },
+            Self::StrengthMight => {
+                char.mechanical_properties.focuses.insert(Focus::StrengthMight, FocusLevel::SingleFocus);
+                true
+            },
+            Self::FightingHeavyBlades => {
+                char.mechanical_properties.focuses.insert(Focus::FightingHeavyBlades, FocusLevel::SingleFocus);
+                true
+            },
+            Self::CommunicationPersuasion => {
+                char.mechanical_properties.focuses.insert(Focus::CommunicationPersuasion, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneConstitution => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Constitution) += 1;
+                true
+            },
+            Self::StrengthClimbing => {
+                char.mechanical_properties.focuses.insert(Focus::StrengthClimbing, FocusLevel::SingleFocus);
+                true
+            },
+            Self::IntelligenceCulturalLore => {
+                char.mechanical_properties.focuses.insert(Focus::IntelligenceCulturalLore, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneCommunication => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Communication) += 1;
+                true
+            },
+            Self::PlusOneDexterity => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Dexterity) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Human-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HumanPower {
+    AdaptableFocus,
+}
+
+impl std::fmt::Display for HumanPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AdaptableFocus => write!(f, "Adaptable Focus (Human)"),
+        }
+    }
+}
+
+/// Details about the various Human powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HumanPowerDetails {
+    pub adaptable_focus: Option<AdaptableFocusDetails>,
+}
+
+impl HumanPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.adaptable_focus),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: HumanPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            HumanPower::AdaptableFocus => into_generic_power_option(&self.adaptable_focus),
+        }
+    }
+}
+
+/// Metadata about the Adaptable Focus power.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdaptableFocusDetails {
+    /// The focus the player chose.
+    pub focus: Focus,
+}
+
+impl PowerMechanics for AdaptableFocusDetails {
+    fn power(&self) -> crate::Power {
+        HumanPower::AdaptableFocus.into()
+    }
+
+    fn name(&self) -> String {
+        "Adaptable Focus (Human)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("Gained the {} focus.", self.focus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, HumanBenefit::PlusOneStrength),
+            (3, HumanBenefit::StrengthMight), (4, HumanBenefit::StrengthMight),
+            (5, HumanBenefit::FightingHeavyBlades),
+            (6, HumanBenefit::CommunicationPersuasion),
+            (7, HumanBenefit::PlusOneConstitution), (8, HumanBenefit::PlusOneConstitution),
+            (9, HumanBenefit::StrengthClimbing),
+            (10, HumanBenefit::IntelligenceCulturalLore),
+            (11, HumanBenefit::PlusOneCommunication),
+            (12, HumanBenefit::PlusOneDexterity),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(HumanBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(HumanBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, AncestryBenefit, AncestryBenefitSelections, Character, DarkSightDetails, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
@@ -13,24 +13,27 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Orc benefits
+    pub benefits: AncestryBenefitSelections<OrcBenefit>,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as Dark Sight and available languages. Sub-advancements cover the user's
 /// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.powers.dark_sight = Some(DarkSightDetails {});
+        char.mechanical_properties.powers.orc.tough = Some(ToughDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -55,7 +58,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Stamina) => Focus::ConstitutionStamina,
@@ -70,3 +73,211 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The Orc benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OrcBenefit {
+    PlusOneConstitution,
+    ConstitutionTolerance,
+    StrengthIntimidation,
+    FightingAxes,
+    PlusOneStrength,
+    PerceptionSmelling,
+    WillpowerCourage,
+    PlusOneFighting,
+    PlusOneAccuracy,
+}
+
+impl std::fmt::Display for OrcBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for OrcBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOneConstitution => "+1 Constitution",
+            Self::ConstitutionTolerance => "Constitution (Tolerance)",
+            Self::StrengthIntimidation => "Strength (Intimidation)",
+            Self::FightingAxes => "Fighting (Axes)",
+            Self::PlusOneStrength => "+1 Strength",
+            Self::PerceptionSmelling => "Perception (Smelling)",
+            Self::WillpowerCourage => "Willpower (Courage)",
+            Self::PlusOneFighting => "+1 Fighting",
+            Self::PlusOneAccuracy => "+1 Accuracy",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOneConstitution,
+            3..=4 => Self::ConstitutionTolerance,
+            5 => Self::StrengthIntimidation,
+            6 => Self::FightingAxes,
+            7..=8 => Self::PlusOneStrength,
+            9 => Self::PerceptionSmelling,
+            10 => Self::WillpowerCourage,
+            11 => Self::PlusOneFighting,
+            12 => Self::PlusOneAccuracy,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOneConstitution => true,
+            Self::ConstitutionTolerance => false,
+            Self::StrengthIntimidation => false,
+            Self::FightingAxes => false,
+            Self::PlusOneStrength => true,
+            Self::PerceptionSmelling => false,
+            Self::WillpowerCourage => false,
+            Self::PlusOneFighting => true,
+            Self::PlusOneAccuracy => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOneConstitution => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Constitution) += 1;
+                true
+            },
+            Self::ConstitutionTolerance => {
+                char.mechanical_properties.focuses.insert(Focus::ConstitutionTolerance, FocusLevel::SingleFocus);
+                true
+            },
+            Self::StrengthIntimidation => {
+                char.mechanical_properties.focuses.insert(Focus::StrengthIntimidation, FocusLevel::SingleFocus);
+                true
+            },
+            Self::FightingAxes => {
+                char.mechanical_properties.focuses.insert(Focus::FightingAxes, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneStrength => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Strength) += 1;
+                true
+            },
+            Self::PerceptionSmelling => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionSmelling, FocusLevel::SingleFocus);
+                true
+            },
+            Self::WillpowerCourage => {
+                char.mechanical_properties.focuses.insert(Focus::WillpowerCourage, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneFighting => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Fighting) += 1;
+                true
+            },
+            Self::PlusOneAccuracy => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Accuracy) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Orc-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OrcPower {
+    Tough,
+}
+
+impl std::fmt::Display for OrcPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tough => write!(f, "Tough (Orc)"),
+        }
+    }
+}
+
+/// Details about the various Orc powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrcPowerDetails {
+    pub tough: Option<ToughDetails>,
+}
+
+impl OrcPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.tough),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: OrcPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            OrcPower::Tough => into_generic_power_option(&self.tough),
+        }
+    }
+}
+
+/// Metadata about the Tough power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ToughDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for ToughDetails {
+    fn power(&self) -> crate::Power {
+        OrcPower::Tough.into()
+    }
+
+    fn name(&self) -> String {
+        "Tough (Orc)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "+5 Health".to_owned()
+    }
+
+    fn health_bonus(&self) -> Option<i8> {
+        Some(5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, OrcBenefit::PlusOneConstitution),
+            (3, OrcBenefit::ConstitutionTolerance), (4, OrcBenefit::ConstitutionTolerance),
+            (5, OrcBenefit::StrengthIntimidation),
+            (6, OrcBenefit::FightingAxes),
+            (7, OrcBenefit::PlusOneStrength), (8, OrcBenefit::PlusOneStrength),
+            (9, OrcBenefit::PerceptionSmelling),
+            (10, OrcBenefit::WillpowerCourage),
+            (11, OrcBenefit::PlusOneFighting),
+            (12, OrcBenefit::PlusOneAccuracy),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(OrcBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(OrcBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{Advancement, Ancestry, Character, Focus, FocusLevel, LeafNodeAdvancement};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, Ancestry, AncestryBenefit, AncestryBenefitSelections, Character, Dice, DiceWithMod, Focus, FocusLevel, LeafNodeAdvancement, PowerMechanics};
 
 #[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -35,6 +35,41 @@ impl Species {
             Self::Ursine => "Ursine",
         }
     }
+
+    /// The natural weapon members of this species attack with.
+    pub fn natural_weapon(&self) -> NaturalWeapon {
+        match self {
+            Self::Avian => NaturalWeapon::Talons,
+            Self::Canine => NaturalWeapon::Bite,
+            Self::Vulpine => NaturalWeapon::Bite,
+            Self::Feline => NaturalWeapon::Claws,
+            Self::Herpestidae => NaturalWeapon::Bite,
+            Self::Rodent => NaturalWeapon::Incisors,
+            Self::Leporidae => NaturalWeapon::Kick,
+            Self::Ungulate => NaturalWeapon::Horns,
+            Self::Ursine => NaturalWeapon::HeavyClaws,
+        }
+    }
+
+    /// Whether members of this species can glide.
+    pub fn can_glide(&self) -> bool {
+        matches!(self, Self::Avian)
+    }
+
+    /// The focuses a member of this species can choose from for their species focus.
+    pub fn focus_options(&self) -> &'static [Focus] {
+        match self {
+            Self::Avian => &[Focus::PerceptionSeeing, Focus::DexterityAcrobatics],
+            Self::Canine => &[Focus::PerceptionSmelling, Focus::PerceptionTracking],
+            Self::Vulpine => &[Focus::PerceptionSmelling, Focus::DexterityStealth],
+            Self::Feline => &[Focus::DexterityAcrobatics, Focus::DexterityStealth],
+            Self::Herpestidae => &[Focus::DexterityInitiative, Focus::ConstitutionTolerance],
+            Self::Rodent => &[Focus::PerceptionHearing, Focus::DexterityStealth],
+            Self::Leporidae => &[Focus::PerceptionHearing, Focus::StrengthJumping],
+            Self::Ungulate => &[Focus::ConstitutionRunning, Focus::StrengthMight],
+            Self::Ursine => &[Focus::StrengthMight, Focus::PerceptionSmelling],
+        }
+    }
 }
 
 impl std::fmt::Display for Species {
@@ -44,12 +79,59 @@ impl std::fmt::Display for Species {
 }
 
 impl LeafNodeAdvancement for Option<Species> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.ancestry = Some(Ancestry::Wildfolk(*self));
         Ok(*self != None)
     }
 }
 
+/// The natural weapons Wildfolk can attack with.
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NaturalWeapon {
+    Bite,
+    Claws,
+    HeavyClaws,
+    Horns,
+    Incisors,
+    Kick,
+    Talons,
+}
+
+impl NaturalWeapon {
+    /// The display name for this natural weapon.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bite => "Bite",
+            Self::Claws => "Claws",
+            Self::HeavyClaws => "Heavy Claws",
+            Self::Horns => "Horns",
+            Self::Incisors => "Incisors",
+            Self::Kick => "Kick",
+            Self::Talons => "Talons",
+        }
+    }
+
+    /// The damage dealt by this natural weapon, before any modifiers.
+    pub fn damage(&self) -> DiceWithMod {
+        match self {
+            Self::Bite => DiceWithMod { dice: Dice::d6(1), modifier: 0 },
+            Self::Claws => DiceWithMod { dice: Dice::d6(1), modifier: 0 },
+            Self::HeavyClaws => DiceWithMod { dice: Dice::d6(1), modifier: 2 },
+            Self::Horns => DiceWithMod { dice: Dice::d6(1), modifier: 1 },
+            Self::Incisors => DiceWithMod { dice: Dice::d3(1), modifier: 1 },
+            Self::Kick => DiceWithMod { dice: Dice::d6(1), modifier: 0 },
+            Self::Talons => DiceWithMod { dice: Dice::d6(1), modifier: 0 },
+        }
+    }
+}
+
+impl std::fmt::Display for NaturalWeapon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 /// The initial selections the user must make for this ancestry.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -60,24 +142,39 @@ pub struct Level1Selections {
     /// Initial ability focus selection.
     pub ability_focus: Option<AbilityFocusSelection>,
 
-    // TODO: Wildfolk benefits
+    pub benefits: AncestryBenefitSelections<WildfolkBenefit>,
+
+    /// The focus chosen from the species' focus options.
+    pub species_focus: SpeciesFocusSelection,
 }
 
 /// This top-level advancement only adds things the user doesn't have to select,
 /// such as available languages. Sub-advancements cover the user's choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        if let Some(species) = self.species {
+            char.mechanical_properties.powers.wildfolk.natural_weapon = Some(
+                NaturalWeaponDetails { weapon: species.natural_weapon() }
+            );
+            if species.can_glide() {
+                char.mechanical_properties.powers.wildfolk.glide = Some(GlideDetails {});
+            }
+        }
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.species);
-        f(&self.ability_focus);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("species", &self.species);
+        f("ability_focus", &self.ability_focus);
+        f("benefits", &self.benefits);
+        f("species_focus", &self.species_focus);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.species);
         f(&mut self.ability_focus);
+        f(&mut self.benefits);
+        f(&mut self.species_focus);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -102,7 +199,7 @@ impl std::fmt::Display for AbilityFocusSelection {
 }
 
 impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let focus = match self {
             Self::None => return Ok(false),
             Self::Some(AbilityFocusSelection::Stamina) => Focus::ConstitutionStamina,
@@ -117,3 +214,265 @@ impl LeafNodeAdvancement for Option<AbilityFocusSelection> {
         }
     }
 }
+
+/// The focus chosen from the species' focus options.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpeciesFocusSelection {
+    pub focus: Option<Focus>,
+}
+
+/// Relies on the species selection having already been applied to the character.
+impl LeafNodeAdvancement for SpeciesFocusSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let focus = match self.focus {
+            None => return Ok(false),
+            Some(focus) => focus,
+        };
+        let species = match char.mechanical_properties.ancestry {
+            Some(Ancestry::Wildfolk(Some(species))) => species,
+            _ => return Err(AdvancementError::NoSpecies),
+        };
+        if !species.focus_options().contains(&focus) {
+            Err(AdvancementError::FocusNotAvailable(focus))
+        }
+        else if char.mechanical_properties.focuses.contains_key(&focus) {
+            Err(AdvancementError::FocusAlreadyKnown(focus))
+        }
+        else {
+            char.mechanical_properties.focuses.insert(focus, FocusLevel::SingleFocus);
+            Ok(true)
+        }
+    }
+}
+
+/// The Wildfolk benefits the user can choose from.
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WildfolkBenefit {
+    PlusOnePerception,
+    AccuracyBrawling,
+    PerceptionSmelling,
+    DexterityAcrobatics,
+    PlusOneDexterity,
+    PerceptionTracking,
+    StrengthJumping,
+    PlusOneConstitution,
+    PlusOneWillpower,
+}
+
+impl std::fmt::Display for WildfolkBenefit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl AncestryBenefit for WildfolkBenefit {
+    /// The display name of this benefit.
+    fn display_name(&self) -> String {
+        match self {
+            Self::PlusOnePerception => "+1 Perception",
+            Self::AccuracyBrawling => "Accuracy (Brawling)",
+            Self::PerceptionSmelling => "Perception (Smelling)",
+            Self::DexterityAcrobatics => "Dexterity (Acrobatics)",
+            Self::PlusOneDexterity => "+1 Dexterity",
+            Self::PerceptionTracking => "Perception (Tracking)",
+            Self::StrengthJumping => "Strength (Jumping)",
+            Self::PlusOneConstitution => "+1 Constitution",
+            Self::PlusOneWillpower => "+1 Willpower",
+        }.to_owned()
+    }
+
+    /// For a given 2d6 roll, the corresponding benefit.
+    fn from_roll(roll: u16) -> Result<Self, ()> {
+        Ok(match roll {
+            2 => Self::PlusOnePerception,
+            3..=4 => Self::AccuracyBrawling,
+            5 => Self::PerceptionSmelling,
+            6 => Self::DexterityAcrobatics,
+            7..=8 => Self::PlusOneDexterity,
+            9 => Self::PerceptionTracking,
+            10 => Self::StrengthJumping,
+            11 => Self::PlusOneConstitution,
+            12 => Self::PlusOneWillpower,
+            _ => return Err(()),
+        })
+    }
+
+    /// Whether this benefit counts as both choices when choosing manually.
+    fn counts_as_two(&self) -> bool {
+        match self {
+            Self::PlusOnePerception => true,
+            Self::AccuracyBrawling => false,
+            Self::PerceptionSmelling => false,
+            Self::DexterityAcrobatics => false,
+            Self::PlusOneDexterity => true,
+            Self::PerceptionTracking => false,
+            Self::StrengthJumping => false,
+            Self::PlusOneConstitution => true,
+            Self::PlusOneWillpower => true,
+        }
+    }
+
+    /// Apply this benefit to the character.
+    ///
+    /// Returns whether the selection is complete.
+    fn apply(&self, char: &mut Character) -> bool {
+        match self {
+            Self::PlusOnePerception => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Perception) += 1;
+                true
+            },
+            Self::AccuracyBrawling => {
+                char.mechanical_properties.focuses.insert(Focus::AccuracyBrawling, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PerceptionSmelling => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionSmelling, FocusLevel::SingleFocus);
+                true
+            },
+            Self::DexterityAcrobatics => {
+                char.mechanical_properties.focuses.insert(Focus::DexterityAcrobatics, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneDexterity => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Dexterity) += 1;
+                true
+            },
+            Self::PerceptionTracking => {
+                char.mechanical_properties.focuses.insert(Focus::PerceptionTracking, FocusLevel::SingleFocus);
+                true
+            },
+            Self::StrengthJumping => {
+                char.mechanical_properties.focuses.insert(Focus::StrengthJumping, FocusLevel::SingleFocus);
+                true
+            },
+            Self::PlusOneConstitution => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Constitution) += 1;
+                true
+            },
+            Self::PlusOneWillpower => {
+                *char.mechanical_properties.abilities.get_mut(Ability::Willpower) += 1;
+                true
+            },
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Wildfolk-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WildfolkPower {
+    Glide,
+    NaturalWeapon,
+}
+
+impl std::fmt::Display for WildfolkPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Glide => write!(f, "Glide (Wildfolk)"),
+            Self::NaturalWeapon => write!(f, "Natural Weapon (Wildfolk)"),
+        }
+    }
+}
+
+/// Details about the various Wildfolk powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WildfolkPowerDetails {
+    pub glide: Option<GlideDetails>,
+    pub natural_weapon: Option<NaturalWeaponDetails>,
+}
+
+impl WildfolkPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.glide),
+            into_generic_power_option(&self.natural_weapon),
+        ]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: WildfolkPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            WildfolkPower::Glide => into_generic_power_option(&self.glide),
+            WildfolkPower::NaturalWeapon => into_generic_power_option(&self.natural_weapon),
+        }
+    }
+}
+
+/// Metadata about the Glide power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GlideDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for GlideDetails {
+    fn power(&self) -> crate::Power {
+        WildfolkPower::Glide.into()
+    }
+
+    fn name(&self) -> String {
+        "Glide (Wildfolk)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "When falling, you can glide up to twice your Speed horizontally and take no falling damage.".to_owned()
+    }
+}
+
+/// Metadata about the Natural Weapon power.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NaturalWeaponDetails {
+    /// The natural weapon the character's species attacks with.
+    pub weapon: NaturalWeapon,
+}
+
+impl PowerMechanics for NaturalWeaponDetails {
+    fn power(&self) -> crate::Power {
+        WildfolkPower::NaturalWeapon.into()
+    }
+
+    fn name(&self) -> String {
+        "Natural Weapon (Wildfolk)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("You can attack with your {} for {} damage.", self.weapon.name().to_lowercase(), self.weapon.damage())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benefit_from_roll() {
+        let table = [
+            (2, WildfolkBenefit::PlusOnePerception),
+            (3, WildfolkBenefit::AccuracyBrawling), (4, WildfolkBenefit::AccuracyBrawling),
+            (5, WildfolkBenefit::PerceptionSmelling),
+            (6, WildfolkBenefit::DexterityAcrobatics),
+            (7, WildfolkBenefit::PlusOneDexterity), (8, WildfolkBenefit::PlusOneDexterity),
+            (9, WildfolkBenefit::PerceptionTracking),
+            (10, WildfolkBenefit::StrengthJumping),
+            (11, WildfolkBenefit::PlusOneConstitution),
+            (12, WildfolkBenefit::PlusOneWillpower),
+        ];
+        for (roll, benefit) in table {
+            assert_eq!(WildfolkBenefit::from_roll(roll), Ok(benefit), "roll {}", roll);
+        }
+        for roll in [0, 1, 13] {
+            assert_eq!(WildfolkBenefit::from_roll(roll), Err(()), "roll {}", roll);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+//! Armor
+
+use strum::EnumIter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Armor {
+    LightLeather,
+    HeavyLeather,
+    LightMail,
+    HeavyMail,
+    LightPlate,
+    HeavyPlate,
+}
+
+impl Armor {
+    pub fn properties(&self) -> ArmorProperties {
+        match self {
+            Self::LightLeather => ArmorProperties {
+                rating: 3,
+                penalty: 0,
+                min_strength: None,
+            },
+            Self::HeavyLeather => ArmorProperties {
+                rating: 4,
+                penalty: 1,
+                min_strength: Some(0),
+            },
+            Self::LightMail => ArmorProperties {
+                rating: 5,
+                penalty: 2,
+                min_strength: Some(1),
+            },
+            Self::HeavyMail => ArmorProperties {
+                rating: 7,
+                penalty: 3,
+                min_strength: Some(2),
+            },
+            Self::LightPlate => ArmorProperties {
+                rating: 8,
+                penalty: 4,
+                min_strength: Some(2),
+            },
+            Self::HeavyPlate => ArmorProperties {
+                rating: 10,
+                penalty: 5,
+                min_strength: Some(3),
+            },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::LightLeather => "Light Leather",
+            Self::HeavyLeather => "Heavy Leather",
+            Self::LightMail => "Light Mail",
+            Self::HeavyMail => "Heavy Mail",
+            Self::LightPlate => "Light Plate",
+            Self::HeavyPlate => "Heavy Plate",
+        }
+    }
+}
+
+impl std::fmt::Display for Armor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArmorProperties {
+    /// How much damage the armor absorbs.
+    pub rating: i8,
+
+    /// How much the armor slows the wearer down.
+    pub penalty: i8,
+
+    /// The strength needed to wear the armor without further penalty.
+    pub min_strength: Option<i8>,
+    // TODO: cost
+}
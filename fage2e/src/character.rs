@@ -7,8 +7,10 @@ use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    Ability, AbilityScores, AdditiveModifier, Ancestry, BaseValue, Class, Focus, FocusLevel, ModifierSet, ModifierSource, PowerDetails, Value, Weapon, WeaponGroup
+    Ability, AbilityScores, AdditiveModifier, Ancestry, Arcana, Armor, AttackType, BaseValue, Class, ConditionalModifier, Dice, DiceRoll, DiceWithMod, Focus, FocusLevel, ModifierSet, ModifierSource, PowerDetails, Shield, Spell, Stunt, StuntSource, Talent, TalentDegree, Value, Weapon, WeaponGroup
 };
+use crate::mage;
+use crate::wildfolk::{NaturalWeapon, WildfolkPower};
 
 /// Non-mechanical properties of a character.
 #[derive(Debug, Clone, PartialEq)]
@@ -16,10 +18,13 @@ use crate::{
 pub struct CharacterFlavor {
     pub name: String,
 
-    // TODO: Apparently, Envoy gets two of each of these.
     pub background: Option<String>,  // TODO: Enum? Variant?
     pub social_class: Option<String>,  // TODO: Enum? Variant?
 
+    /// Envoys get a second background and social class from their Well Connected power.
+    pub second_background: Option<String>,
+    pub second_social_class: Option<String>,
+
     pub backstory: Option<String>,
 }
 
@@ -51,22 +56,40 @@ pub struct CharacterMechanicalProperties {
     /// The defense advancements the character has earned over the levels.
     pub defense_advancements: Vec<AdditiveModifier>,
 
+    /// The magic point advancements the character has earned over the levels.
+    pub magic_point_advancements: Vec<AdditiveModifier>,
+
     /// Details about the various powers the character has.
     pub powers: PowerDetails,
 
-    // TODO: MP
-    // TODO: Spells
+    /// The character's talents and how far they've advanced in each.
+    pub talents: HashMap<Talent, TalentDegree>,
+
+    /// The stunts the character has learned and where each came from.
+    pub stunts: HashMap<Stunt, StuntSource>,
+
+    /// The arcana the character has learned.
+    pub arcana: HashSet<Arcana>,
+
+    /// The spells the character knows.
+    pub spells: HashSet<Spell>,
 }
 
 /// The character's equipment.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CharacterEquipment {
     /// The character's weapon cache.
     pub weapons: Vec<Weapon>,
 
-    // TODO: armor
-    // TODO: shields
+    /// The armor the character is wearing, if any.
+    pub armor: Option<Armor>,
+
+    /// The shield the character is carrying, if any.
+    pub shield: Option<Shield>,
+
+    /// The weapon from the weapon cache the character has in hand, if any.
+    pub wielded: Option<Weapon>,
 }
 
 /// On-going stats about a character.
@@ -103,6 +126,8 @@ impl Character {
                 name: String::new(),
                 background: None,
                 social_class: None,
+                second_background: None,
+                second_social_class: None,
                 backstory: None,
             },
             mechanical_properties: CharacterMechanicalProperties {
@@ -114,10 +139,18 @@ impl Character {
                 weapon_training: HashSet::new(),
                 health_advancements: Vec::new(),
                 defense_advancements: Vec::new(),
+                magic_point_advancements: Vec::new(),
                 powers: PowerDetails::default(),
+                talents: HashMap::new(),
+                stunts: HashMap::new(),
+                arcana: HashSet::new(),
+                spells: HashSet::new(),
             },
             equipment: CharacterEquipment {
                 weapons: Vec::new(),
+                armor: None,
+                shield: None,
+                wielded: None,
             },
             status: CharacterStatus {
                 exp: 0,
@@ -128,10 +161,18 @@ impl Character {
 
     /// The character's maximum health.
     pub fn max_health(&self) -> Value {
-        let modifiers = ModifierSet {
+        let mut modifiers = ModifierSet {
             override_: None,
             additive: self.mechanical_properties.health_advancements.clone(),
+            halved: None,
+            conditional: Vec::new(),
         };
+        modifiers.additive.extend(self.mechanical_properties.powers.iter().filter_map(|power| {
+            power.health_bonus().map(|bonus| AdditiveModifier {
+                value: bonus,
+                source: ModifierSource::Power(power.power()),
+            })
+        }));
         if let Some(class) = self.mechanical_properties.class {
             Value {
                 base: BaseValue {
@@ -149,12 +190,35 @@ impl Character {
         }
     }
 
+    /// The character's maximum magic points.
+    pub fn max_magic_points(&self) -> Value {
+        let modifiers = ModifierSet {
+            override_: None,
+            additive: self.mechanical_properties.magic_point_advancements.clone(),
+            halved: None,
+            conditional: Vec::new(),
+        };
+        let class = self.mechanical_properties.class;
+        match class.and_then(|class| class.initial_base_magic_points().map(|mp| (class, mp))) {
+            Some((class, mp)) => Value {
+                base: BaseValue {
+                    value: mp as i16,
+                    source: ModifierSource::Class(class),
+                },
+                modifiers
+            },
+            None => Value {
+                base: BaseValue { value: 0, source: ModifierSource::Core },
+                modifiers
+            },
+        }
+    }
+
     /// The modifiers that go into the character's move speed.
     pub fn speed_yards(&self) -> Value {
         // Per Chapter 1, Step 7 (Defense and Speed), speed is:
         //   base speed (from ancestry) + dexterity - armor penalty
-        // TODO: Armor penalty
-        let modifiers = ModifierSet {
+        let mut modifiers = ModifierSet {
             override_: None,
             additive: vec![
                 AdditiveModifier {
@@ -162,7 +226,16 @@ impl Character {
                     source: ModifierSource::Ability(Ability::Dexterity),
                 },
             ],
+            halved: None,
+            conditional: Vec::new(),
         };
+        modifiers.additive.extend(self.armor_penalty());
+        modifiers.additive.extend(self.mechanical_properties.powers.iter().filter_map(|power| {
+            power.speed_bonus().map(|bonus| AdditiveModifier {
+                value: bonus,
+                source: ModifierSource::Power(power.power()),
+            })
+        }));
         if let Some(ancestry) = self.mechanical_properties.ancestry {
             Value {
                 base: BaseValue {
@@ -204,10 +277,11 @@ impl Character {
     pub fn defense(&self) -> Value {
         // Per Chapter 1, Step 7 (Defense and Speed), defense is:
         //   base defense + dexterity + shield bonus
-        // TODO: Shield bonus
         let mut modifiers = ModifierSet {
             override_: None,
             additive: self.mechanical_properties.defense_advancements.clone(),
+            halved: None,
+            conditional: Vec::new(),
         };
         modifiers.additive.push(
             AdditiveModifier {
@@ -215,25 +289,392 @@ impl Character {
                 source: ModifierSource::Ability(Ability::Dexterity),
             }
         );
+        if let Some(shield) = self.equipment.shield {
+            let properties = shield.properties();
+            modifiers.additive.push(
+                AdditiveModifier {
+                    value: properties.defense_bonus,
+                    source: ModifierSource::Equipment(shield.into()),
+                }
+            );
+            // Carrying a shield you aren't strong enough for costs you some of its benefit,
+            // but can't leave you worse off than carrying no shield at all.
+            if let Some(min_strength) = properties.min_strength {
+                let strength = self.mechanical_properties.abilities.get(Ability::Strength).score;
+                if strength < min_strength {
+                    modifiers.additive.push(
+                        AdditiveModifier {
+                            value: (strength - min_strength).max(-properties.defense_bonus),
+                            source: ModifierSource::Ability(Ability::Strength),
+                        }
+                    );
+                }
+            }
+        }
         Value {
             base: BaseValue { value: 10, source: ModifierSource::Core },
             modifiers
         }
     }
 
+    /// The character's defense against a particular kind of attack.
+    ///
+    /// This is the character's general defense plus any bonuses that only apply against
+    /// that kind of attack, such as a wielded spiked buckler's bonus against melee attacks.
+    pub fn defense_vs(&self, attack: AttackType) -> Value {
+        let mut defense = self.defense();
+        if attack == AttackType::Melee {
+            // Only the weapon in hand counts; the rest of the weapon cache doesn't help.
+            let wielded = self.equipment.wielded
+                .filter(|weapon| self.equipment.weapons.contains(weapon));
+            if let Some((weapon, bonus)) = wielded.and_then(|weapon| Some((weapon, weapon.melee_defense_bonus()?))) {
+                defense.modifiers.additive.push(
+                    AdditiveModifier {
+                        value: bonus,
+                        source: ModifierSource::Equipment(weapon.into()),
+                    }
+                );
+            }
+        }
+        defense
+    }
+
     /// The character's armor rating.
     pub fn armor(&self) -> Value {
+        let mut additive: Vec<_> = self.mechanical_properties.powers.iter().filter_map(|power| {
+            power.armor_bonus().map(|bonus| AdditiveModifier {
+                value: bonus,
+                source: ModifierSource::Power(power.power()),
+            })
+        }).collect();
+        if let Some(armor) = self.equipment.armor {
+            additive.push(AdditiveModifier {
+                value: armor.properties().rating,
+                source: ModifierSource::Equipment(armor.into()),
+            });
+        }
         Value {
             base: BaseValue { value: 0, source: ModifierSource::Core },
             modifiers: ModifierSet {
                 override_: None,
-                additive: self.mechanical_properties.powers.iter().filter_map(|power| {
-                    power.armor_bonus().map(|bonus| AdditiveModifier {
-                        value: bonus,
-                        source: ModifierSource::Power(power.power()),
-                    })
-                }).collect(),
+                additive,
+                halved: None,
+                conditional: Vec::new(),
             },
         }
     }
+
+    /// The penalties the character suffers from the armor they're wearing.
+    ///
+    /// On top of the armor's own penalty, wearing armor without the strength for it
+    /// adds the difference as a further penalty.
+    pub fn armor_penalty(&self) -> Vec<AdditiveModifier> {
+        let armor = match self.equipment.armor {
+            None => return Vec::new(),
+            Some(armor) => armor,
+        };
+        let properties = armor.properties();
+        let mut penalties = Vec::new();
+        if properties.penalty != 0 {
+            penalties.push(AdditiveModifier {
+                value: -properties.penalty,
+                source: ModifierSource::Equipment(armor.into()),
+            });
+            // Powers can cancel out the penalty, but can't turn it into a bonus.
+            let mut remaining = properties.penalty;
+            for power in self.mechanical_properties.powers.iter() {
+                if let Some(reduction) = power.armor_penalty_reduction() {
+                    let reduction = reduction.min(remaining);
+                    if reduction > 0 {
+                        remaining -= reduction;
+                        penalties.push(AdditiveModifier {
+                            value: reduction,
+                            source: ModifierSource::Power(power.power()),
+                        });
+                    }
+                }
+            }
+        }
+        if let Some(min_strength) = properties.min_strength {
+            let strength = self.mechanical_properties.abilities.get(Ability::Strength).score;
+            if strength < min_strength {
+                penalties.push(AdditiveModifier {
+                    value: strength - min_strength,
+                    source: ModifierSource::Ability(Ability::Strength),
+                });
+            }
+        }
+        penalties
+    }
+
+    /// The modifier a focus adds to tests, if the character has it.
+    pub fn focus_modifier(&self, focus: Focus) -> Option<AdditiveModifier> {
+        self.mechanical_properties.focuses.get(&focus).map(|level| AdditiveModifier {
+            value: level.bonus(),
+            source: ModifierSource::Focus(focus),
+        })
+    }
+
+    /// The 3d6 roll for the given ability plus the given focus.
+    ///
+    /// This is the basis for tests and attack rolls alike. The focus's bonus only applies if
+    /// the character actually has it.
+    fn ability_roll(&self, ability: Ability, focus: Focus) -> DiceRoll {
+        let mut modifiers = ModifierSet {
+            override_: None,
+            additive: vec![
+                AdditiveModifier {
+                    value: self.mechanical_properties.abilities.get(ability).score,
+                    source: ModifierSource::Ability(ability),
+                },
+            ],
+            halved: None,
+            conditional: Vec::new(),
+        };
+        modifiers.additive.extend(self.focus_modifier(focus));
+        DiceRoll {
+            base: BaseValue {
+                value: DiceWithMod { dice: Dice::d6(3), modifier: 0 },
+                source: ModifierSource::Core,
+            },
+            modifiers,
+        }
+    }
+
+    /// The roll for a basic ability test using the given focus.
+    ///
+    /// The focus's bonus only applies if the character actually has it.
+    pub fn test_roll(&self, focus: Focus) -> DiceRoll {
+        self.ability_roll(focus.ability(), focus)
+    }
+
+    /// The modifiers the character's talents grant, in talent name order.
+    fn talent_modifiers(&self, bonus: impl Fn(&Talent, TalentDegree) -> Option<i8>) -> Vec<AdditiveModifier> {
+        let mut talents: Vec<_> = self.mechanical_properties.talents.iter().collect();
+        talents.sort_by_key(|(talent, _)| talent.name());
+        talents.into_iter().filter_map(|(talent, degree)| {
+            bonus(talent, *degree).map(|value| AdditiveModifier {
+                value,
+                source: ModifierSource::Talent(*talent),
+            })
+        }).collect()
+    }
+
+    /// The character's spellpower for the given arcana.
+    pub fn spellpower(&self, arcana: Arcana) -> Value {
+        // Spellpower is:
+        //   10 + willpower + arcana focus
+        let mut modifiers = ModifierSet {
+            override_: None,
+            additive: vec![
+                AdditiveModifier {
+                    value: self.mechanical_properties.abilities.get(Ability::Willpower).score,
+                    source: ModifierSource::Ability(Ability::Willpower),
+                },
+            ],
+            halved: None,
+            conditional: Vec::new(),
+        };
+        modifiers.additive.extend(self.focus_modifier(arcana.focus()));
+        modifiers.additive.extend(self.talent_modifiers(Talent::spellpower_bonus));
+        Value {
+            base: BaseValue { value: 10, source: ModifierSource::Core },
+            modifiers
+        }
+    }
+
+    /// The roll for casting the given spell.
+    ///
+    /// The result has to meet or beat the spell's target number.
+    pub fn casting_test(&self, spell: Spell) -> DiceRoll {
+        // A casting test is an ordinary arcana test, plus any talents that help with casting.
+        let mut roll = self.test_roll(spell.arcana().focus());
+        roll.modifiers.additive.extend(self.talent_modifiers(Talent::casting_test_bonus));
+        roll
+    }
+
+    /// The roll for resisting a spell, if the character has a power that helps with that.
+    pub fn magical_resistance_test(&self) -> Option<DiceRoll> {
+        self.mechanical_properties.powers.iter()
+            .find_map(|power| power.magical_resistance_focus().map(|focus| (power.power(), focus)))
+            .map(|(power, focus)| {
+                let mut roll = self.test_roll(focus);
+                roll.base.source = ModifierSource::Power(power);
+                roll
+            })
+    }
+
+    /// The roll for attacking with the given weapon.
+    pub fn attack_roll(&self, weapon: Weapon) -> DiceRoll {
+        let group = weapon.group();
+        let mut roll = self.ability_roll(group.attack_ability(), group.focus());
+
+        // Fighting with a weapon you haven't trained with is hard.
+        if !self.mechanical_properties.weapon_training.contains(&group) {
+            roll.modifiers.additive.push(
+                AdditiveModifier {
+                    value: -2,
+                    source: ModifierSource::Untrained(group),
+                }
+            );
+        }
+
+        // So is fighting with a weapon you aren't strong enough to wield.
+        if let Some(min_strength) = weapon.properties().min_strength {
+            let strength = self.mechanical_properties.abilities.get(Ability::Strength).score;
+            if strength < min_strength {
+                roll.modifiers.additive.push(
+                    AdditiveModifier {
+                        value: strength - min_strength,
+                        source: ModifierSource::Ability(Ability::Strength),
+                    }
+                );
+            }
+        }
+
+        roll
+    }
+
+    /// The extra damage the character's powers add to attacks when their conditions are met.
+    fn power_conditional_damage(&self) -> Vec<ConditionalModifier<DiceWithMod>> {
+        self.mechanical_properties.powers.iter().filter_map(|power| {
+            power.conditional_damage(self).map(|damage| ConditionalModifier {
+                value: damage,
+                source: ModifierSource::Power(power.power()),
+            })
+        }).collect()
+    }
+
+    /// The roll for damage dealt with the given weapon.
+    pub fn damage_roll(&self, weapon: Weapon) -> DiceRoll {
+        let group = weapon.group();
+        let ability = group.damage_ability();
+        let mut modifiers = ModifierSet {
+            override_: None,
+            additive: vec![
+                AdditiveModifier {
+                    value: self.mechanical_properties.abilities.get(ability).score,
+                    source: ModifierSource::Ability(ability),
+                },
+            ],
+            halved: None,
+            conditional: self.power_conditional_damage(),
+        };
+
+        // Weapons you haven't trained with only do half damage.
+        if !self.mechanical_properties.weapon_training.contains(&group) {
+            modifiers.halved = Some(ModifierSource::Untrained(group));
+        }
+
+        DiceRoll {
+            base: BaseValue {
+                value: weapon.properties().damage,
+                source: ModifierSource::Equipment(weapon.into()),
+            },
+            modifiers,
+        }
+    }
+
+    /// The natural weapon the character can attack with, if any.
+    pub fn natural_weapon(&self) -> Option<NaturalWeapon> {
+        self.mechanical_properties.powers.wildfolk.natural_weapon.as_ref().map(|details| details.weapon)
+    }
+
+    /// The roll for attacking with a natural weapon.
+    ///
+    /// Natural weapons use the Brawling group, but never count as untrained.
+    pub fn natural_weapon_attack_roll(&self) -> DiceRoll {
+        let group = WeaponGroup::Brawling;
+        self.ability_roll(group.attack_ability(), group.focus())
+    }
+
+    /// The roll for damage dealt with the given natural weapon.
+    pub fn natural_weapon_damage_roll(&self, weapon: NaturalWeapon) -> DiceRoll {
+        let ability = WeaponGroup::Brawling.damage_ability();
+        let modifiers = ModifierSet {
+            override_: None,
+            additive: vec![
+                AdditiveModifier {
+                    value: self.mechanical_properties.abilities.get(ability).score,
+                    source: ModifierSource::Ability(ability),
+                },
+            ],
+            halved: None,
+            conditional: self.power_conditional_damage(),
+        };
+        DiceRoll {
+            base: BaseValue {
+                value: weapon.damage(),
+                source: ModifierSource::Power(WildfolkPower::NaturalWeapon.into()),
+            },
+            modifiers,
+        }
+    }
+
+    /// How many SP the given stunt costs the character.
+    ///
+    /// Powers can make stunts cheaper, but never cheaper than 1 SP.
+    pub fn stunt_sp_cost(&self, stunt: Stunt) -> Value {
+        let mut additive = Vec::new();
+        let mut remaining = stunt.sp_cost().saturating_sub(1);
+        for power in self.mechanical_properties.powers.iter() {
+            if let Some(reduction) = power.stunt_cost_reduction(stunt) {
+                let reduction = reduction.min(remaining);
+                if reduction > 0 {
+                    remaining -= reduction;
+                    additive.push(AdditiveModifier {
+                        value: -(reduction as i8),
+                        source: ModifierSource::Power(power.power()),
+                    });
+                }
+            }
+        }
+        Value {
+            base: BaseValue { value: stunt.sp_cost() as i16, source: ModifierSource::Stunt(stunt) },
+            modifiers: ModifierSet { override_: None, additive, halved: None, conditional: Vec::new() },
+        }
+    }
+
+    /// The roll for damage dealt by a stunt, if the stunt deals damage on its own.
+    pub fn stunt_damage_roll(&self, stunt: Stunt) -> Option<DiceRoll> {
+        let damage = stunt.damage()?;
+        let additive = stunt.damage_ability().map(|ability| AdditiveModifier {
+            value: self.mechanical_properties.abilities.get(ability).score,
+            source: ModifierSource::Ability(ability),
+        });
+        Some(DiceRoll {
+            base: BaseValue { value: damage, source: ModifierSource::Stunt(stunt) },
+            modifiers: ModifierSet { override_: None, additive: additive.into_iter().collect(), halved: None, conditional: Vec::new() },
+        })
+    }
+
+    /// The roll for attacking with Arcane Blast, if the character has that power.
+    pub fn arcane_blast_attack_roll(&self) -> Option<DiceRoll> {
+        self.mechanical_properties.powers.mage.arcane_blast.as_ref()?;
+        let focus = Focus::AccuracyArcaneBlast;
+        Some(self.ability_roll(focus.ability(), focus))
+    }
+
+    /// The roll for damage dealt with Arcane Blast, if the character has that power.
+    pub fn arcane_blast_damage_roll(&self) -> Option<DiceRoll> {
+        self.mechanical_properties.powers.mage.arcane_blast.as_ref()?;
+        let modifiers = ModifierSet {
+            override_: None,
+            additive: vec![
+                AdditiveModifier {
+                    value: self.mechanical_properties.abilities.get(Ability::Willpower).score,
+                    source: ModifierSource::Ability(Ability::Willpower),
+                },
+            ],
+            halved: None,
+            conditional: Vec::new(),
+        };
+        Some(DiceRoll {
+            base: BaseValue {
+                value: mage::ARCANE_BLAST_DAMAGE,
+                source: ModifierSource::Power(mage::MagePower::ArcaneBlast.into()),
+            },
+            modifiers,
+        })
+    }
 }
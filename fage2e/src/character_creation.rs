@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
-use crate::{Character, Dice, Ability, AbilityScore, Advancement, LeafNodeAdvancement, WeaponGroup};
+use crate::{Character, Dice, Ability, AbilityScore, Advancement, AdvancementError, LeafNodeAdvancement, WeaponGroup};
 
 /// Character name selection
 #[derive(Debug, Clone, Default)]
@@ -16,7 +16,7 @@ pub struct SelectName
 }
 
 impl LeafNodeAdvancement for SelectName {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.flavor.name = self.name.clone();
         Ok(!self.name.is_empty())
     }
@@ -31,19 +31,128 @@ pub struct SelectAbilities {
     pub advancements: [Option<Ability>; 13],
 }
 
-impl LeafNodeAdvancement for SelectAbilities {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+/// Each of the 13 advancements is its own sub-advancement, so that problems can be traced
+/// back to the advancement that caused them.
+impl Advancement for SelectAbilities {
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
+        // Everything happens in the individual advancements.
+        Ok(true)
+    }
+
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        for (i, ability) in self.advancements.iter().enumerate() {
+            f(&format!("advancement{}", i + 1), &SelectAbility { ability: *ability });
+        }
+    }
+
+    fn foreach_mut(&mut self, _: &mut dyn FnMut(&mut dyn Advancement)) {
+        // The sub-advancements are just views of `advancements`, so there's nothing to
+        // hand out mutably.
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+/// A single one of the advancements in SelectAbilities.
+#[derive(Debug, Clone, Default)]
+pub struct SelectAbility {
+    pub ability: Option<Ability>,
+}
+
+impl LeafNodeAdvancement for SelectAbility {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let ability = match self.ability {
+            None => return Ok(false),
+            Some(ability) => ability,
+        };
         let abilities = &mut char.mechanical_properties.abilities;
-        let mut all_done = true;
-        for adv in self.advancements {
-            if let Some(ability) = adv {
-                if abilities.get(ability).score >= 3 {
-                    return Err(());
-                }
-                abilities.get_mut(ability).score += 1;
+        if abilities.get(ability).score >= 3 {
+            return Err(AdvancementError::AbilityAboveMaximum { ability, max: 3 });
+        }
+        abilities.get_mut(ability).score += 1;
+        Ok(true)
+    }
+}
+
+/// The player has chosen to roll for their abilities.
+///
+/// They roll 3d6 once for each ability, in order, and look up the starting score for
+/// each total. Optionally, they may then swap the results for two abilities.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollAbilities {
+    /// The 3d6 totals, in the same order as `Ability::iter()`.
+    pub rolls: [Option<u8>; 9],
+
+    /// Which two abilities to swap results between, if any.
+    pub swap: Option<(Ability, Ability)>,
+}
+
+impl RollAbilities {
+    /// The dice roll to use for each ability.
+    pub fn dice() -> Dice {
+        Dice::d6(3)
+    }
+
+    /// The starting ability score for a given 3d6 total.
+    ///
+    /// Per the Starting Ability Scores table in Chapter 1, Step 1.
+    pub fn score_for_roll(roll: u8) -> Option<i8> {
+        Some(match roll {
+            3 => -2,
+            4..=5 => -1,
+            6..=8 => 0,
+            9..=11 => 1,
+            12..=14 => 2,
+            15..=17 => 3,
+            18 => 4,
+            _ => return None,
+        })
+    }
+
+    /// Randomly roll all nine abilities.
+    ///
+    /// The user can still choose to swap two results afterward.
+    #[cfg(feature = "rand")]
+    pub fn roll() -> Self {
+        let mut rolls = [None; 9];
+        for roll in rolls.iter_mut() {
+            *roll = Some(Self::dice().roll_all_sum() as u8);
+        }
+        Self { rolls, swap: None }
+    }
+}
+
+impl LeafNodeAdvancement for RollAbilities {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let mut scores = [None; 9];
+        for (score, roll) in scores.iter_mut().zip(self.rolls) {
+            if let Some(roll) = roll {
+                let dice = Self::dice();
+                *score = Some(Self::score_for_roll(roll).ok_or(AdvancementError::RollOutOfRange {
+                    roll: roll as i16,
+                    min: dice.min_value(),
+                    max: dice.max_value(),
+                })?);
             }
-            else {
-                all_done = false;
+        }
+
+        if let Some((ability1, ability2)) = self.swap {
+            // Swapping an ability with itself doesn't make sense.
+            if ability1 == ability2 {
+                return Err(AdvancementError::SwapWithSelf(ability1));
+            }
+            let idx1 = Ability::iter().position(|a| a == ability1).unwrap();
+            let idx2 = Ability::iter().position(|a| a == ability2).unwrap();
+            scores.swap(idx1, idx2);
+        }
+
+        let abilities = &mut char.mechanical_properties.abilities;
+        let mut all_done = true;
+        for (ability, score) in Ability::iter().zip(scores) {
+            match score {
+                Some(score) => abilities.set(ability, AbilityScore { score, partial: 0 }),
+                None => all_done = false,
             }
         }
         Ok(all_done)
@@ -68,7 +177,7 @@ pub struct ManuallyEnterAbilities {
 }
 
 impl LeafNodeAdvancement for ManuallyEnterAbilities {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         let abilities = &mut char.mechanical_properties.abilities;
         abilities.set(Ability::Accuracy, AbilityScore { score: self.accuracy, partial: 0});
         abilities.set(Ability::Communication, AbilityScore { score: self.communication, partial: 0});
@@ -90,7 +199,7 @@ pub enum AbilityDetermination {
     #[default]
     NoChoice,
     Select(SelectAbilities),
-    // TODO: Dice-roll based method.
+    Roll(RollAbilities),
     Manual(ManuallyEnterAbilities),
 }
 
@@ -98,16 +207,17 @@ pub enum AbilityDetermination {
 /// has advantages in the GUI code, though, because the user does need to decide how to decide
 /// abilities.
 impl Advancement for AbilityDetermination {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
-        // Nothing to do here.
-        Ok(true)
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
+        // Nothing to do here other than make sure a method was chosen.
+        Ok(!matches!(self, Self::NoChoice))
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
         match self {
             Self::NoChoice => (),
-            Self::Select(s) => f(s),
-            Self::Manual(s) => f(s),
+            Self::Select(s) => f("select", s),
+            Self::Roll(s) => f("roll", s),
+            Self::Manual(s) => f("manual", s),
         }
     }
 
@@ -115,6 +225,7 @@ impl Advancement for AbilityDetermination {
         match self {
             Self::NoChoice => (),
             Self::Select(s) => f(s),
+            Self::Roll(s) => f(s),
             Self::Manual(s) => f(s),
         }
     }
@@ -144,7 +255,7 @@ pub trait InitialWeaponGroups {
 }
 
 impl<T: InitialWeaponGroups> LeafNodeAdvancement for T {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         // Apply the always-get training.
         for weapon_group in Self::always_get() {
             char.mechanical_properties.weapon_training.insert(*weapon_group);
@@ -153,7 +264,7 @@ impl<T: InitialWeaponGroups> LeafNodeAdvancement for T {
         // Apply the player's choices, keeping track of whether there were any
         // unselected choices or errors.
         let mut any_unselected = false;
-        let mut any_err = false;
+        let mut first_err = None;
         for maybe_weapon_group in self.choices() {
             let weapon_group = match maybe_weapon_group {
                 None => { any_unselected = true; continue; },
@@ -162,14 +273,14 @@ impl<T: InitialWeaponGroups> LeafNodeAdvancement for T {
 
             // It's an error if the user selects something outside the valid selection set.
             if !Self::choose_between().contains(&weapon_group) {
-                any_err = true;
+                first_err.get_or_insert(AdvancementError::WeaponGroupNotSelectable(*weapon_group));
                 continue;
             }
 
             char.mechanical_properties.weapon_training.insert(*weapon_group);
         }
 
-        return if any_err { Err(()) } else { Ok(!any_unselected) };
+        return match first_err { Some(err) => Err(err), None => Ok(!any_unselected) };
     }
 }
 
@@ -240,58 +351,125 @@ impl <B> AncestryBenefitSelections<B>
     }
 }
 
-impl <B> LeafNodeAdvancement for AncestryBenefitSelections<B>
-    where B : std::fmt::Debug + Clone + Copy + PartialEq + AncestryBenefit
+/// Each selection is its own sub-advancement, so that problems can be traced back to the
+/// selection that caused them.
+impl <B> Advancement for AncestryBenefitSelections<B>
+    where B : std::fmt::Debug + Clone + Copy + PartialEq + AncestryBenefit + 'static
 {
-    fn apply(&self, char: &mut Character) -> Result<bool, ()> {
-        // Apply the first selection, if it was selected.
-        if let Some(selection1) = self.selection1 {
-            let selection1_done = selection1.apply(char);
-
-            // The user can't select the same benefit twice.
-            if self.selection1 == self.selection2 {
-                return Err(());
-            }
+    fn apply_self(&self, _: &mut Character) -> Result<bool, AdvancementError> {
+        // Everything happens in the individual selections.
+        Ok(true)
+    }
 
-            // If this counts as two selections and the user didn't roll for
-            // their selections...
-            if selection1.counts_as_two() && !self.selections_were_rolled {
-                // ... then it's an error if the user made a second selection.
-                if self.selection2.is_some() {
-                    return Err(());
-                }
-                // Otherwise, the selections are done if the first selection
-                // is done.
-                else {
-                    return Ok(selection1_done);
-                }
-            }
-            // Otherwise...
-            else {
-                // ... apply the second selection, if it was selected.
-                if let Some(selection2) = self.selection2 {
-                    let selection2_done = selection2.apply(char);
-                    return Ok(selection1_done && selection2_done);
-                }
-                // Otherwise, the user isn't done.
-                else {
-                    return Ok(false);
-                }
-            }
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("selection1", &AncestryBenefitSelection { selections: *self, second: false });
+        f("selection2", &AncestryBenefitSelection { selections: *self, second: true });
+    }
+
+    fn foreach_mut(&mut self, _: &mut dyn FnMut(&mut dyn Advancement)) {
+        // The sub-advancements are just views of the selections, so there's nothing to
+        // hand out mutably.
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+/// A single one of the selections in AncestryBenefitSelections.
+///
+/// Whether a selection is valid (or even needed) depends on the other selection, so this
+/// carries all of them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AncestryBenefitSelection<B>
+    where B : AncestryBenefit
+{
+    pub selections: AncestryBenefitSelections<B>,
+
+    /// Whether this is the second selection rather than the first.
+    pub second: bool,
+}
+
+impl <B> LeafNodeAdvancement for AncestryBenefitSelection<B>
+    where B : std::fmt::Debug + Clone + Copy + PartialEq + AncestryBenefit + 'static
+{
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let AncestryBenefitSelections { selection1, selection2, selections_were_rolled } = self.selections;
+
+        // A benefit that counts as two selections only does so when the user chose it.
+        let counts_as_two = |selection: Option<B>| {
+            selection.is_some_and(|selection| selection.counts_as_two()) && !selections_were_rolled
+        };
+
+        if !self.second {
+            return match selection1 {
+                Some(selection1) => Ok(selection1.apply(char)),
+                // The first selection isn't needed if the second counts as both.
+                None => Ok(counts_as_two(selection2)),
+            };
         }
-        // If there was no first selection...
-        else {
-            // ... apply the second selection, if it was selected.
-            if let Some(selection2) = self.selection2 {
-                let selection2_done = selection2.apply(char);
-                // The user is done IFF selection2 is done, selection2 counts as two
-                // benefits, AND the user did not roll for their selections.
-                return Ok(selection2_done && selection2.counts_as_two() && !self.selections_were_rolled);
-            }
-            // Otherwise, the user isn't done.
-            else {
-                return Ok(false);
-            }
+
+        let selection2 = match selection2 {
+            // The second selection isn't needed if the first counts as both.
+            None => return Ok(counts_as_two(selection1)),
+            Some(selection2) => selection2,
+        };
+
+        // The user can't select the same benefit twice.
+        if selection1 == Some(selection2) {
+            return Err(AdvancementError::DuplicateAncestryBenefit(selection2.display_name()));
+        }
+
+        // Nor can they make a second selection if the first counts as both.
+        if let Some(selection1) = selection1.filter(|_| counts_as_two(selection1)) {
+            return Err(AdvancementError::BenefitCountsAsTwo(selection1.display_name()));
+        }
+
+        Ok(selection2.apply(char))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_for_roll() {
+        let table = [
+            (3, -2),
+            (4, -1), (5, -1),
+            (6, 0), (7, 0), (8, 0),
+            (9, 1), (10, 1), (11, 1),
+            (12, 2), (13, 2), (14, 2),
+            (15, 3), (16, 3), (17, 3),
+            (18, 4),
+        ];
+        for (roll, score) in table {
+            assert_eq!(RollAbilities::score_for_roll(roll), Some(score), "roll {}", roll);
         }
+        for roll in [0, 2, 19, u8::MAX] {
+            assert_eq!(RollAbilities::score_for_roll(roll), None, "roll {}", roll);
+        }
+    }
+
+    #[test]
+    fn roll_abilities_swap() {
+        let mut rolls = [Some(10); 9];
+        // Accuracy comes first in `Ability::iter()`.
+        rolls[0] = Some(18);
+        let adv = RollAbilities { rolls, swap: Some((Ability::Accuracy, Ability::Willpower)) };
+        let mut char = Character::new();
+        assert_eq!(adv.apply(&mut char), Ok(true));
+        assert_eq!(char.mechanical_properties.abilities.get(Ability::Willpower).score, 4);
+        assert_eq!(char.mechanical_properties.abilities.get(Ability::Accuracy).score, 1);
+    }
+
+    #[test]
+    fn roll_abilities_out_of_range() {
+        let mut rolls = [Some(10); 9];
+        rolls[3] = Some(19);
+        let adv = RollAbilities { rolls, swap: None };
+        assert_eq!(
+            adv.apply(&mut Character::new()),
+            Err(AdvancementError::RollOutOfRange { roll: 19, min: 3, max: 18 }),
+        );
     }
 }
@@ -5,7 +5,7 @@ use strum::EnumIter;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::Ability;
+use crate::{Ability, Power, PowerMechanics, Stunt, StuntType};
 
 pub mod envoy;
 pub mod mage;
@@ -61,6 +61,37 @@ impl Class {
             Self::Warrior => warrior::STARTING_HEALTH,
         }
     }
+
+    /// This class's initial base magic points, if the class uses magic at all.
+    /// The character's base magic points will be this + Willpower + d6.
+    pub fn initial_base_magic_points(&self) -> Option<u8> {
+        match self {
+            Self::Envoy => envoy::STARTING_MAGIC_POINTS,
+            Self::Mage => mage::STARTING_MAGIC_POINTS,
+            Self::Rogue => rogue::STARTING_MAGIC_POINTS,
+            Self::Warrior => warrior::STARTING_MAGIC_POINTS,
+        }
+    }
+
+    /// The optional advancements this class gains at the given level (2-20).
+    pub fn level_advancements(&self, level: u8) -> LevelAdvancements {
+        match self {
+            Self::Envoy => envoy::level_advancements(level),
+            Self::Mage => mage::level_advancements(level),
+            Self::Rogue => rogue::level_advancements(level),
+            Self::Warrior => warrior::level_advancements(level),
+        }
+    }
+
+    /// The powers this class gains automatically at the given level (2-20).
+    pub fn powers_gained_at(&self, level: u8) -> &'static [ClassPowerGrant] {
+        match self {
+            Self::Envoy => envoy::powers_gained_at(level),
+            Self::Mage => mage::powers_gained_at(level),
+            Self::Rogue => rogue::powers_gained_at(level),
+            Self::Warrior => warrior::powers_gained_at(level),
+        }
+    }
 }
 
 impl std::fmt::Display for Class {
@@ -68,3 +99,87 @@ impl std::fmt::Display for Class {
         write!(f, "{}", self.name())
     }
 }
+
+/// Which of the optional advancements a class gains at a particular level.
+///
+/// Every level from 2 onward also comes with health, ability, and focus advancements;
+/// those are the same for all classes, so they aren't listed here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LevelAdvancements {
+    pub defense: bool,
+    pub talent: bool,
+    pub specialization: bool,
+    pub stunt: bool,
+    pub out_of_class_stunt: bool,
+    pub magic_points: bool,
+    pub spell: bool,
+}
+
+/// A power a class gains automatically upon reaching a particular level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassPowerGrant {
+    /// The power gained.
+    pub power: Power,
+
+    /// What the power does.
+    pub description: &'static str,
+
+    /// The part of the power that shows up on the character sheet, if any.
+    pub effect: Option<ClassPowerEffect>,
+}
+
+/// The mechanical effects a class power gained from leveling up can have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassPowerEffect {
+    /// Stunts of the given type cost this many fewer SP.
+    StuntCostReduction(StuntType, u8),
+}
+
+/// Metadata about a power the character gained automatically from leveling up.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClassPowerDetails {
+    pub power: Power,
+
+    /// The level the power was gained at.
+    pub level: u8,
+}
+
+impl ClassPowerDetails {
+    /// The class's progression table entry for this power.
+    pub fn grant(&self) -> Option<&'static ClassPowerGrant> {
+        let class = match self.power {
+            Power::Envoy(_) => Class::Envoy,
+            Power::Mage(_) => Class::Mage,
+            Power::Rogue(_) => Class::Rogue,
+            Power::Warrior(_) => Class::Warrior,
+            _ => return None,
+        };
+        class.powers_gained_at(self.level).iter().find(|grant| grant.power == self.power)
+    }
+}
+
+impl PowerMechanics for ClassPowerDetails {
+    fn power(&self) -> Power {
+        self.power
+    }
+
+    fn name(&self) -> String {
+        format!("Level {}: {}", self.level, self.power)
+    }
+
+    fn description(&self) -> String {
+        match self.grant() {
+            Some(grant) => grant.description.to_owned(),
+            None => String::new(),
+        }
+    }
+
+    fn stunt_cost_reduction(&self, stunt: Stunt) -> Option<u8> {
+        match self.grant()?.effect? {
+            ClassPowerEffect::StuntCostReduction(stunt_type, reduction) if stunt.stunt_type() == stunt_type =>
+                Some(reduction),
+            _ => None,
+        }
+    }
+}
@@ -3,8 +3,9 @@
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::EnumIter;
 
-use crate::{Ability, Advancement, Character, InitialWeaponGroups, WeaponGroup};
+use crate::{grant_starting_talent, into_generic_power_option, Ability, Advancement, AdvancementError, Character, ClassPowerDetails, ClassPowerEffect, ClassPowerGrant, Focus, InitialWeaponGroups, LeafNodeAdvancement, LevelAdvancements, Power, PowerMechanics, SpecializationDetails, StuntType, Talent, TalentDegree, TalentRequirement, WeaponGroup};
 
 pub static PRIMARY_ABILITIES: [Ability; 4] = [
     Ability::Communication, Ability::Fighting, Ability::Intelligence, Ability::Willpower,
@@ -14,29 +15,79 @@ pub static SECONDARY_ABILITIES: [Ability; 5] = [
 ];
 
 pub static STARTING_HEALTH: u8 = 25;
+pub static STARTING_MAGIC_POINTS: Option<u8> = None;
+
+/// The optional advancements this class gains at the given level.
+///
+/// Per the class's Level Advancement table in Chapter 1/Classes.
+pub fn level_advancements(level: u8) -> LevelAdvancements {
+    LevelAdvancements {
+        defense: matches!(level, 10 | 20),
+        talent: matches!(level, 3 | 7 | 11 | 15 | 19),
+        specialization: matches!(level, 4 | 6 | 8 | 12 | 14 | 16),
+        stunt: matches!(level, 5 | 13),
+        out_of_class_stunt: matches!(level, 9 | 17),
+        magic_points: false,
+        spell: false,
+    }
+}
+
+/// The powers this class gains automatically at the given level.
+///
+/// Level 1 powers come from the class's Level1Selections instead, so they aren't listed here.
+pub fn powers_gained_at(level: u8) -> &'static [ClassPowerGrant] {
+    match level {
+        2 => &[ClassPowerGrant {
+            power: Power::Envoy(EnvoyPower::Inspire),
+            description: "As a major action, rouse your allies with a speech; each ally who can hear you gains +1 on their next test.",
+            effect: None,
+        }],
+        5 => &[ClassPowerGrant {
+            power: Power::Envoy(EnvoyPower::ReadTheRoom),
+            description: "When you enter a social encounter, you may ask the GM who holds the most influence there.",
+            effect: None,
+        }],
+        11 => &[ClassPowerGrant {
+            power: Power::Envoy(EnvoyPower::SilverTongue),
+            description: "Roleplaying stunts cost you 1 less SP, to a minimum of 1.",
+            effect: Some(ClassPowerEffect::StuntCostReduction(StuntType::Roleplaying, 1)),
+        }],
+        20 => &[ClassPowerGrant {
+            power: Power::Envoy(EnvoyPower::LivingLegend),
+            description: "Your reputation precedes you; most people you meet have heard of your deeds and treat you accordingly.",
+            effect: None,
+        }],
+        _ => &[],
+    }
+}
 
 /// The initial selections the user must make for this class.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Level1Selections {
     pub weapon_groups: WeaponGroupSelection,
-    // TODO: Level 1 powers
-    // TODO: Starting Specialization
-    // TODO: Starting Talents
+    pub starting_talent: StartingTalentSelection,
+    pub starting_specialization: StartingSpecializationSelection,
 }
 
-/// This top-level advancement doesn't itself have any logic, but it has sub-advancements.
+/// This top-level advancement grants the Envoy's level 1 powers, which don't involve
+/// any choices. Sub-advancements cover the user's choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        char.mechanical_properties.powers.envoy.well_connected = Some(WellConnectedDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.weapon_groups);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("weapon_groups", &self.weapon_groups);
+        f("starting_talent", &self.starting_talent);
+        f("starting_specialization", &self.starting_specialization);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.weapon_groups);
+        f(&mut self.starting_talent);
+        f(&mut self.starting_specialization);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -81,3 +132,190 @@ impl InitialWeaponGroups for WeaponGroupSelection {
         &mut self.choices_
     }
 }
+
+/// The talents an Envoy can choose from at level 1.
+pub static STARTING_TALENTS: [Talent; 4] = [
+    Talent::Command, Talent::Contacts, Talent::Intrigue, Talent::Oratory,
+];
+
+/// The talent an Envoy starts with, at Novice degree.
+///
+/// Starting talents don't have to meet the talent's usual requirements.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StartingTalentSelection {
+    pub talent: Option<Talent>,
+}
+
+impl LeafNodeAdvancement for StartingTalentSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let talent = match self.talent {
+            None => return Ok(false),
+            Some(t) => t,
+        };
+        grant_starting_talent(char, talent, &STARTING_TALENTS)?;
+        Ok(true)
+    }
+}
+
+/// The specialization an Envoy starts with, at Novice degree.
+///
+/// Starting specializations don't have to meet the specialization's usual requirements.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StartingSpecializationSelection {
+    pub specialization: Option<EnvoySpecialization>,
+}
+
+impl LeafNodeAdvancement for StartingSpecializationSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let specialization = match self.specialization {
+            None => return Ok(false),
+            Some(s) => s.into(),
+        };
+        char.mechanical_properties.powers.specializations.push(
+            SpecializationDetails { specialization, degree: TalentDegree::Novice }
+        );
+        Ok(true)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Envoy-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnvoyPower {
+    WellConnected,
+
+    // Powers gained after level 1.
+    Inspire,
+    ReadTheRoom,
+    SilverTongue,
+    LivingLegend,
+}
+
+impl std::fmt::Display for EnvoyPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WellConnected => write!(f, "Well Connected (Envoy)"),
+            Self::Inspire => write!(f, "Inspire (Envoy)"),
+            Self::ReadTheRoom => write!(f, "Read the Room (Envoy)"),
+            Self::SilverTongue => write!(f, "Silver Tongue (Envoy)"),
+            Self::LivingLegend => write!(f, "Living Legend (Envoy)"),
+        }
+    }
+}
+
+/// Details about the various Envoy powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvoyPowerDetails {
+    pub well_connected: Option<WellConnectedDetails>,
+
+    /// The powers gained automatically from leveling up, in the order they were gained.
+    pub level_powers: Vec<ClassPowerDetails>,
+}
+
+impl EnvoyPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.well_connected),
+        ]
+            .into_iter()
+            .flatten()
+            .chain(self.level_powers.iter().map(|p| p as &dyn PowerMechanics))
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: EnvoyPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            EnvoyPower::WellConnected => into_generic_power_option(&self.well_connected),
+            // Powers gained after level 1 come from leveling up.
+            EnvoyPower::Inspire |
+            EnvoyPower::ReadTheRoom |
+            EnvoyPower::SilverTongue |
+            EnvoyPower::LivingLegend => self.level_powers.iter()
+                .find(|details| details.power == power.into())
+                .map(|details| details as &dyn PowerMechanics),
+        }
+    }
+}
+
+/// Metadata about the Well Connected power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WellConnectedDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for WellConnectedDetails {
+    fn power(&self) -> crate::Power {
+        EnvoyPower::WellConnected.into()
+    }
+
+    fn name(&self) -> String {
+        "Well Connected (Envoy)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "You have two backgrounds and two social classes, and the contacts that come with them.".to_owned()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SPECIALIZATIONS
+// -----------------------------------------------------------------------------
+
+/// Envoy-specific specializations.
+#[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnvoySpecialization {
+    Agent,
+    Commander,
+    Courtier,
+}
+
+impl EnvoySpecialization {
+    /// The display name for this specialization.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Agent => "Agent",
+            Self::Commander => "Commander",
+            Self::Courtier => "Courtier",
+        }
+    }
+
+    /// What a character needs before they can take this specialization.
+    pub fn requirements(&self) -> &'static [TalentRequirement] {
+        match self {
+            Self::Agent => &[TalentRequirement::Focus(Focus::CommunicationDeception)],
+            Self::Commander => &[TalentRequirement::Focus(Focus::CommunicationLeadership)],
+            Self::Courtier => &[TalentRequirement::Focus(Focus::CommunicationEtiquette)],
+        }
+    }
+
+    /// The benefit granted at the given degree of this specialization.
+    pub fn benefit(&self, degree: TalentDegree) -> &'static str {
+        match (self, degree) {
+            (Self::Agent, TalentDegree::Novice) => "You are practiced at working undercover and gathering secrets.",
+            (Self::Agent, TalentDegree::Expert) => "Your cover stories hold up even under close scrutiny.",
+            (Self::Agent, TalentDegree::Master) => "You can slip into and out of any social circle at will.",
+            (Self::Commander, TalentDegree::Novice) => "Your orders steady your allies in battle.",
+            (Self::Commander, TalentDegree::Expert) => "Your allies fight harder when they can hear your voice.",
+            (Self::Commander, TalentDegree::Master) => "Your presence on the battlefield can turn a rout into a rally.",
+            (Self::Courtier, TalentDegree::Novice) => "You know how to make a good impression at court.",
+            (Self::Courtier, TalentDegree::Expert) => "You can read the shifting alliances of a room at a glance.",
+            (Self::Courtier, TalentDegree::Master) => "Nobles seek out your favor and your counsel.",
+        }
+    }
+}
+
+impl std::fmt::Display for EnvoySpecialization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -3,8 +3,9 @@
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::EnumIter;
 
-use crate::{Ability, Advancement, Character, InitialWeaponGroups, WeaponGroup};
+use crate::{into_generic_power_option, Ability, Advancement, AdvancementError, Arcana, Character, ClassPowerDetails, ClassPowerEffect, ClassPowerGrant, Dice, DiceWithMod, Focus, InitialWeaponGroups, LeafNodeAdvancement, LevelAdvancements, MagicPointsAdvancement, Power, PowerMechanics, StuntType, TalentDegree, TalentRequirement, WeaponGroup};
 
 pub static PRIMARY_ABILITIES: [Ability; 4] = [
     Ability::Accuracy, Ability::Intelligence, Ability::Perception, Ability::Willpower,
@@ -14,31 +15,152 @@ pub static SECONDARY_ABILITIES: [Ability; 5] = [
 ];
 
 pub static STARTING_HEALTH: u8 = 20;
+pub static STARTING_MAGIC_POINTS: Option<u8> = Some(10);
+
+/// The optional advancements this class gains at the given level.
+///
+/// Per the class's Level Advancement table in Chapter 1/Classes.
+pub fn level_advancements(level: u8) -> LevelAdvancements {
+    LevelAdvancements {
+        defense: matches!(level, 10 | 20),
+        talent: matches!(level, 3 | 7 | 11 | 15 | 19),
+        specialization: matches!(level, 4 | 6 | 8 | 12 | 14 | 16),
+        stunt: matches!(level, 5 | 13),
+        out_of_class_stunt: matches!(level, 9 | 17),
+        magic_points: true,
+        // Novice spells come with the arcana, so these cover each known arcana's expert
+        // spell and then, once they open up at level 11, its master spell.
+        spell: matches!(level, 6 | 10 | 14 | 18),
+    }
+}
+
+/// The powers this class gains automatically at the given level.
+///
+/// Level 1 powers come from the class's Level1Selections instead, so they aren't listed here.
+pub fn powers_gained_at(level: u8) -> &'static [ClassPowerGrant] {
+    match level {
+        2 => &[ClassPowerGrant {
+            power: Power::Mage(MagePower::SpellExpertise),
+            description: "Once per encounter, you may re-roll a failed casting test and keep the better result.",
+            effect: None,
+        }],
+        6 => &[ClassPowerGrant {
+            power: Power::Mage(MagePower::ArcaneShield),
+            description: "As a minor action, spend 2 MP to gain +2 Defense until the beginning of your next turn.",
+            effect: None,
+        }],
+        12 => &[ClassPowerGrant {
+            power: Power::Mage(MagePower::EmpoweredBlast),
+            description: "Your Arcane Blast can hit a second enemy within 2 yards of the first; roll damage separately for each.",
+            effect: None,
+        }],
+        20 => &[ClassPowerGrant {
+            power: Power::Mage(MagePower::Archmage),
+            description: "Spell stunts cost you 1 less SP, to a minimum of 1.",
+            effect: Some(ClassPowerEffect::StuntCostReduction(StuntType::Spell, 1)),
+        }],
+        _ => &[],
+    }
+}
 
 /// The initial selections the user must make for this class.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Level1Selections {
     pub weapon_groups: WeaponGroupSelection,
+
+    pub arcana: ArcanaSelection,
+
+    // The class determines base magic points; this handles the magic points on top of that.
+    pub magic_points: MagicPointsAdvancement,
 }
 
-/// This top-level advancement doesn't itself have any logic, but it has sub-advancements.
+/// This top-level advancement grants the Mage's level 1 powers, which don't involve
+/// any choices. Sub-advancements cover the user's choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        char.mechanical_properties.powers.mage.arcane_blast = Some(ArcaneBlastDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.weapon_groups);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("weapon_groups", &self.weapon_groups);
+        f("arcana", &self.arcana);
+        f("magic_points", &self.magic_points);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.weapon_groups);
+        f(&mut self.arcana);
+        f(&mut self.magic_points);
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+/// The initial arcana selection for this class.
+///
+/// Learning an arcana also teaches the character all of that arcana's novice spells.
+/// Once both are chosen, the character gains the Magic Training power for them.
+///
+/// Each choice is its own sub-advancement, so that problems can be traced back to the
+/// choice that caused them.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArcanaSelection {
+    pub choices: [Option<Arcana>; 2],
+}
+
+impl Advancement for ArcanaSelection {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        // The choices themselves are learned by the sub-advancements.
+        if let [Some(first), Some(second)] = self.choices {
+            if first != second {
+                char.mechanical_properties.powers.mage.magic_training = Some(MagicTrainingDetails {
+                    arcana: vec![first, second],
+                });
+            }
+        }
+        Ok(true)
+    }
+
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        for (i, arcana) in self.choices.iter().enumerate() {
+            f(&format!("choice{}", i + 1), &ArcanaChoice { arcana: *arcana });
+        }
+    }
+
+    fn foreach_mut(&mut self, _: &mut dyn FnMut(&mut dyn Advancement)) {
+        // The sub-advancements are just views of `choices`, so there's nothing to hand out
+        // mutably.
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
 }
 
+/// A single one of the choices in ArcanaSelection.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArcanaChoice {
+    pub arcana: Option<Arcana>,
+}
+
+impl LeafNodeAdvancement for ArcanaChoice {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let arcana = match self.arcana {
+            None => return Ok(false),
+            Some(arcana) => arcana,
+        };
+        if !char.mechanical_properties.arcana.insert(arcana) {
+            // Can't pick the same arcana twice.
+            return Err(AdvancementError::DuplicateArcana(arcana));
+        }
+        char.mechanical_properties.spells.extend(
+            arcana.spells().filter(|spell| spell.degree() == TalentDegree::Novice)
+        );
+        Ok(true)
+    }
+}
+
 /// The initial weapon group selection for this class.
 #[derive(Debug, Clone, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -70,3 +192,180 @@ impl InitialWeaponGroups for WeaponGroupSelection {
         &mut self.choices_
     }
 }
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// How far away in yards an Arcane Blast can hit a target.
+pub static ARCANE_BLAST_RANGE_YARDS: u16 = 16;
+
+/// The damage dealt by an Arcane Blast, before Willpower is added.
+pub static ARCANE_BLAST_DAMAGE: DiceWithMod = DiceWithMod { dice: Dice::d6(1), modifier: 0 };
+
+/// Mage-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MagePower {
+    ArcaneBlast,
+    MagicTraining,
+
+    // Powers gained after level 1.
+    SpellExpertise,
+    ArcaneShield,
+    EmpoweredBlast,
+    Archmage,
+}
+
+impl std::fmt::Display for MagePower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ArcaneBlast => write!(f, "Arcane Blast (Mage)"),
+            Self::MagicTraining => write!(f, "Magic Training (Mage)"),
+            Self::SpellExpertise => write!(f, "Spell Expertise (Mage)"),
+            Self::ArcaneShield => write!(f, "Arcane Shield (Mage)"),
+            Self::EmpoweredBlast => write!(f, "Empowered Blast (Mage)"),
+            Self::Archmage => write!(f, "Archmage (Mage)"),
+        }
+    }
+}
+
+/// Details about the various Mage powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MagePowerDetails {
+    pub arcane_blast: Option<ArcaneBlastDetails>,
+    pub magic_training: Option<MagicTrainingDetails>,
+
+    /// The powers gained automatically from leveling up, in the order they were gained.
+    pub level_powers: Vec<ClassPowerDetails>,
+}
+
+impl MagePowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.arcane_blast),
+            into_generic_power_option(&self.magic_training),
+        ]
+            .into_iter()
+            .flatten()
+            .chain(self.level_powers.iter().map(|p| p as &dyn PowerMechanics))
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: MagePower) -> Option<&dyn PowerMechanics> {
+        match power {
+            MagePower::ArcaneBlast => into_generic_power_option(&self.arcane_blast),
+            MagePower::MagicTraining => into_generic_power_option(&self.magic_training),
+            // Powers gained after level 1 come from leveling up.
+            MagePower::SpellExpertise |
+            MagePower::ArcaneShield |
+            MagePower::EmpoweredBlast |
+            MagePower::Archmage => self.level_powers.iter()
+                .find(|details| details.power == power.into())
+                .map(|details| details as &dyn PowerMechanics),
+        }
+    }
+}
+
+/// Metadata about the Arcane Blast power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArcaneBlastDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for ArcaneBlastDetails {
+    fn power(&self) -> crate::Power {
+        MagePower::ArcaneBlast.into()
+    }
+
+    fn name(&self) -> String {
+        "Arcane Blast (Mage)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "As a major action, blast a target within {} yards with an Accuracy (Arcane Blast) attack for {} + Willpower damage. Costs no magic points.",
+            ARCANE_BLAST_RANGE_YARDS, ARCANE_BLAST_DAMAGE,
+        )
+    }
+}
+
+/// Metadata about the Magic Training power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MagicTrainingDetails {
+    /// The arcana the character started with.
+    pub arcana: Vec<Arcana>,
+}
+
+impl PowerMechanics for MagicTrainingDetails {
+    fn power(&self) -> crate::Power {
+        MagePower::MagicTraining.into()
+    }
+
+    fn name(&self) -> String {
+        "Magic Training (Mage)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        let arcana: Vec<_> = self.arcana.iter().map(|arcana| arcana.to_string()).collect();
+        format!("You know the {} and can cast their spells by spending magic points.", arcana.join(" and "))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SPECIALIZATIONS
+// -----------------------------------------------------------------------------
+
+/// Mage-specific specializations.
+#[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MageSpecialization {
+    Healer,
+    Necromancer,
+    Shapeshifter,
+}
+
+impl MageSpecialization {
+    /// The display name for this specialization.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Healer => "Healer",
+            Self::Necromancer => "Necromancer",
+            Self::Shapeshifter => "Shapeshifter",
+        }
+    }
+
+    /// What a character needs before they can take this specialization.
+    pub fn requirements(&self) -> &'static [TalentRequirement] {
+        match self {
+            Self::Healer => &[TalentRequirement::Focus(Focus::IntelligenceHealing)],
+            Self::Necromancer => &[TalentRequirement::Ability(Ability::Willpower, 2)],
+            Self::Shapeshifter => &[TalentRequirement::Ability(Ability::Constitution, 1)],
+        }
+    }
+
+    /// The benefit granted at the given degree of this specialization.
+    pub fn benefit(&self, degree: TalentDegree) -> &'static str {
+        match (self, degree) {
+            (Self::Healer, TalentDegree::Novice) => "Your healing magic restores more health.",
+            (Self::Healer, TalentDegree::Expert) => "You can cure diseases and poisons with your magic.",
+            (Self::Healer, TalentDegree::Master) => "You can pull allies back from the brink of death.",
+            (Self::Necromancer, TalentDegree::Novice) => "You can speak with the spirits of the recently dead.",
+            (Self::Necromancer, TalentDegree::Expert) => "You can drain life from your foes to fuel your magic.",
+            (Self::Necromancer, TalentDegree::Master) => "You can raise the dead to serve you for a time.",
+            (Self::Shapeshifter, TalentDegree::Novice) => "You can take the form of a small animal.",
+            (Self::Shapeshifter, TalentDegree::Expert) => "You can take the form of a large or dangerous animal.",
+            (Self::Shapeshifter, TalentDegree::Master) => "You can shift forms in the blink of an eye.",
+        }
+    }
+}
+
+impl std::fmt::Display for MageSpecialization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
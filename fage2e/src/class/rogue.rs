@@ -3,8 +3,9 @@
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::EnumIter;
 
-use crate::{Ability, Advancement, Character, InitialWeaponGroups, WeaponGroup};
+use crate::{grant_starting_talent, into_generic_power_option, Ability, Advancement, AdvancementError, Armor, Character, ClassPowerDetails, ClassPowerGrant, Dice, DiceWithMod, Focus, InitialWeaponGroups, LeafNodeAdvancement, LevelAdvancements, Power, PowerMechanics, Talent, TalentDegree, TalentRequirement, WeaponGroup};
 
 pub static PRIMARY_ABILITIES: [Ability; 4] = [
     Ability::Accuracy, Ability::Communication, Ability::Dexterity, Ability::Perception,
@@ -14,26 +15,78 @@ pub static SECONDARY_ABILITIES: [Ability; 5] = [
 ];
 
 pub static STARTING_HEALTH: u8 = 25;
+pub static STARTING_MAGIC_POINTS: Option<u8> = None;
+
+/// The optional advancements this class gains at the given level.
+///
+/// Per the class's Level Advancement table in Chapter 1/Classes.
+pub fn level_advancements(level: u8) -> LevelAdvancements {
+    LevelAdvancements {
+        defense: matches!(level, 5 | 10 | 15 | 20),
+        talent: matches!(level, 3 | 7 | 11 | 15 | 19),
+        specialization: matches!(level, 4 | 6 | 8 | 12 | 14 | 16),
+        stunt: matches!(level, 5 | 13),
+        out_of_class_stunt: matches!(level, 9 | 17),
+        magic_points: false,
+        spell: false,
+    }
+}
+
+/// The powers this class gains automatically at the given level.
+///
+/// Level 1 powers come from the class's Level1Selections instead, so they aren't listed here.
+pub fn powers_gained_at(level: u8) -> &'static [ClassPowerGrant] {
+    match level {
+        2 => &[ClassPowerGrant {
+            power: Power::Rogue(RoguePower::Bluff),
+            description: "As a minor action, make an opposed Communication (Deception) test against an enemy; if you win, your next attack against them counts as a Pinpoint Attack regardless of Dexterity.",
+            effect: None,
+        }],
+        8 => &[ClassPowerGrant {
+            power: Power::Rogue(RoguePower::Evasion),
+            description: "When an area effect would damage you, you take half damage if you make a successful Dexterity (Acrobatics) test.",
+            effect: None,
+        }],
+        12 => &[ClassPowerGrant {
+            power: Power::Rogue(RoguePower::LethalStrike),
+            description: "Your Pinpoint Attack deals an extra 1d6 damage.",
+            // Folded into Pinpoint Attack's damage rather than being a bonus of its own.
+            effect: None,
+        }],
+        16 => &[ClassPowerGrant {
+            power: Power::Rogue(RoguePower::Untouchable),
+            description: "Once per encounter, you can turn a hit against you into a miss.",
+            effect: None,
+        }],
+        _ => &[],
+    }
+}
 
 /// The initial selections the user must make for this class.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Level1Selections {
     pub weapon_groups: WeaponGroupSelection,
+    pub starting_talent: StartingTalentSelection,
 }
 
-/// This top-level advancement doesn't itself have any logic, but it has sub-advancements.
+/// This top-level advancement grants the Rogue's level 1 powers, which don't involve
+/// any choices. Sub-advancements cover the user's choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        char.mechanical_properties.powers.rogue.pinpoint_attack = Some(PinpointAttackDetails {});
+        char.mechanical_properties.powers.rogue.rogues_armor = Some(RoguesArmorDetails {});
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.weapon_groups);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("weapon_groups", &self.weapon_groups);
+        f("starting_talent", &self.starting_talent);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.weapon_groups);
+        f(&mut self.starting_talent);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -78,3 +131,226 @@ impl InitialWeaponGroups for WeaponGroupSelection {
         &mut self.choices_
     }
 }
+
+/// The talents a Rogue can choose from at level 1.
+pub static STARTING_TALENTS: [Talent; 4] = [
+    Talent::Contacts, Talent::Scouting, Talent::Thievery, Talent::DualWeaponStyle,
+];
+
+/// The talent a Rogue starts with, at Novice degree.
+///
+/// Starting talents don't have to meet the talent's usual requirements.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StartingTalentSelection {
+    pub talent: Option<Talent>,
+}
+
+impl LeafNodeAdvancement for StartingTalentSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let talent = match self.talent {
+            None => return Ok(false),
+            Some(t) => t,
+        };
+        grant_starting_talent(char, talent, &STARTING_TALENTS)?;
+        Ok(true)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// How much damage Pinpoint Attack adds.
+pub static PINPOINT_ATTACK_DAMAGE: DiceWithMod = DiceWithMod { dice: Dice::d6(1), modifier: 0 };
+
+/// Whether the given armor is light enough for a Rogue to use Pinpoint Attack in.
+///
+/// Per the Rogue's Armor power: only leather armor, or no armor at all.
+pub fn is_rogue_armor(armor: Option<Armor>) -> bool {
+    matches!(armor, None | Some(Armor::LightLeather) | Some(Armor::HeavyLeather))
+}
+
+/// How much extra damage Lethal Strike adds to a Pinpoint Attack.
+pub static LETHAL_STRIKE_DAMAGE: DiceWithMod = DiceWithMod { dice: Dice::d6(1), modifier: 0 };
+
+/// Rogue-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoguePower {
+    PinpointAttack,
+    RoguesArmor,
+
+    // Powers gained after level 1.
+    Bluff,
+    Evasion,
+    LethalStrike,
+    Untouchable,
+}
+
+impl std::fmt::Display for RoguePower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PinpointAttack => write!(f, "Pinpoint Attack (Rogue)"),
+            Self::RoguesArmor => write!(f, "Rogue's Armor (Rogue)"),
+            Self::Bluff => write!(f, "Bluff (Rogue)"),
+            Self::Evasion => write!(f, "Evasion (Rogue)"),
+            Self::LethalStrike => write!(f, "Lethal Strike (Rogue)"),
+            Self::Untouchable => write!(f, "Untouchable (Rogue)"),
+        }
+    }
+}
+
+/// Details about the various Rogue powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoguePowerDetails {
+    pub pinpoint_attack: Option<PinpointAttackDetails>,
+    pub rogues_armor: Option<RoguesArmorDetails>,
+
+    /// The powers gained automatically from leveling up, in the order they were gained.
+    pub level_powers: Vec<ClassPowerDetails>,
+}
+
+impl RoguePowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.pinpoint_attack),
+            into_generic_power_option(&self.rogues_armor),
+        ]
+            .into_iter()
+            .flatten()
+            .chain(self.level_powers.iter().map(|p| p as &dyn PowerMechanics))
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: RoguePower) -> Option<&dyn PowerMechanics> {
+        match power {
+            RoguePower::PinpointAttack => into_generic_power_option(&self.pinpoint_attack),
+            RoguePower::RoguesArmor => into_generic_power_option(&self.rogues_armor),
+            // Powers gained after level 1 come from leveling up.
+            RoguePower::Bluff |
+            RoguePower::Evasion |
+            RoguePower::LethalStrike |
+            RoguePower::Untouchable => self.level_powers.iter()
+                .find(|details| details.power == power.into())
+                .map(|details| details as &dyn PowerMechanics),
+        }
+    }
+}
+
+/// Metadata about the Pinpoint Attack power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PinpointAttackDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for PinpointAttackDetails {
+    fn power(&self) -> crate::Power {
+        RoguePower::PinpointAttack.into()
+    }
+
+    fn name(&self) -> String {
+        "Pinpoint Attack (Rogue)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Once per round, add {} damage to an attack against a target whose Dexterity is lower than yours.",
+            PINPOINT_ATTACK_DAMAGE.dice,
+        )
+    }
+
+    /// Whether an attack qualifies depends on the target, so this is only a conditional
+    /// modifier. Rogue's Armor rules it out entirely in heavier armor.
+    fn conditional_damage(&self, char: &Character) -> Option<DiceWithMod> {
+        if !is_rogue_armor(char.equipment.armor) {
+            return None;
+        }
+        let mut damage = PINPOINT_ATTACK_DAMAGE;
+        if char.mechanical_properties.powers.rogue.lookup(RoguePower::LethalStrike).is_some() {
+            damage = DiceWithMod {
+                dice: Dice::d6(damage.dice.count() + LETHAL_STRIKE_DAMAGE.dice.count()),
+                modifier: damage.modifier + LETHAL_STRIKE_DAMAGE.modifier,
+            };
+        }
+        Some(damage)
+    }
+}
+
+/// Metadata about the Rogue's Armor power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoguesArmorDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for RoguesArmorDetails {
+    fn power(&self) -> crate::Power {
+        RoguePower::RoguesArmor.into()
+    }
+
+    fn name(&self) -> String {
+        "Rogue's Armor (Rogue)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "You can only use Pinpoint Attack while wearing leather armor or no armor.".to_owned()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SPECIALIZATIONS
+// -----------------------------------------------------------------------------
+
+/// Rogue-specific specializations.
+#[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RogueSpecialization {
+    Assassin,
+    Bard,
+    Scout,
+}
+
+impl RogueSpecialization {
+    /// The display name for this specialization.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Assassin => "Assassin",
+            Self::Bard => "Bard",
+            Self::Scout => "Scout",
+        }
+    }
+
+    /// What a character needs before they can take this specialization.
+    pub fn requirements(&self) -> &'static [TalentRequirement] {
+        match self {
+            Self::Assassin => &[TalentRequirement::Focus(Focus::DexterityStealth)],
+            Self::Bard => &[TalentRequirement::Focus(Focus::CommunicationPerformance)],
+            Self::Scout => &[TalentRequirement::Focus(Focus::PerceptionSeeing)],
+        }
+    }
+
+    /// The benefit granted at the given degree of this specialization.
+    pub fn benefit(&self, degree: TalentDegree) -> &'static str {
+        match (self, degree) {
+            (Self::Assassin, TalentDegree::Novice) => "You are skilled at striking unaware targets.",
+            (Self::Assassin, TalentDegree::Expert) => "You know how to use poisons to deadly effect.",
+            (Self::Assassin, TalentDegree::Master) => "Your strikes from the shadows can fell even the toughest foes.",
+            (Self::Bard, TalentDegree::Novice) => "Your performances can lift the spirits of your allies.",
+            (Self::Bard, TalentDegree::Expert) => "Your songs can sap the will of your enemies.",
+            (Self::Bard, TalentDegree::Master) => "Your performances are the stuff of legend.",
+            (Self::Scout, TalentDegree::Novice) => "You are skilled at moving quickly and quietly through the wilderness.",
+            (Self::Scout, TalentDegree::Expert) => "You can lead your allies safely past danger.",
+            (Self::Scout, TalentDegree::Master) => "You are never caught by surprise in the wild.",
+        }
+    }
+}
+
+impl std::fmt::Display for RogueSpecialization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
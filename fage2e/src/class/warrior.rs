@@ -3,8 +3,9 @@
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
 
-use crate::{Ability, Advancement, Character, InitialWeaponGroups, WeaponGroup};
+use crate::{grant_starting_talent, into_generic_power_option, learn_stunt, Ability, Advancement, AdvancementError, Character, Class, ClassPowerDetails, ClassPowerEffect, ClassPowerGrant, Focus, InitialWeaponGroups, LeafNodeAdvancement, LevelAdvancements, Power, PowerMechanics, Stunt, StuntSource, StuntType, Talent, TalentDegree, TalentRequirement, WeaponGroup};
 
 pub static PRIMARY_ABILITIES: [Ability; 4] = [
     Ability::Constitution, Ability::Dexterity, Ability::Fighting, Ability::Strength,
@@ -14,26 +15,81 @@ pub static SECONDARY_ABILITIES: [Ability; 5] = [
 ];
 
 pub static STARTING_HEALTH: u8 = 30;
+pub static STARTING_MAGIC_POINTS: Option<u8> = None;
+
+/// The optional advancements this class gains at the given level.
+///
+/// Per the class's Level Advancement table in Chapter 1/Classes.
+pub fn level_advancements(level: u8) -> LevelAdvancements {
+    LevelAdvancements {
+        defense: matches!(level, 5 | 10 | 15 | 20),
+        talent: matches!(level, 3 | 7 | 11 | 15 | 19),
+        specialization: matches!(level, 4 | 6 | 8 | 12 | 14 | 16),
+        stunt: matches!(level, 5 | 13),
+        out_of_class_stunt: matches!(level, 9 | 17),
+        magic_points: false,
+        spell: false,
+    }
+}
+
+/// The powers this class gains automatically at the given level.
+///
+/// Level 1 powers come from the class's Level1Selections instead, so they aren't listed here.
+pub fn powers_gained_at(level: u8) -> &'static [ClassPowerGrant] {
+    match level {
+        2 => &[ClassPowerGrant {
+            power: Power::Warrior(WarriorPower::Resilient),
+            description: "Once per encounter, as a minor action, regain health equal to your Constitution + your level.",
+            effect: None,
+        }],
+        4 => &[ClassPowerGrant {
+            power: Power::Warrior(WarriorPower::BattleHardened),
+            description: "You gain +1 on Willpower (Courage) and Willpower (Morale) tests in combat.",
+            effect: None,
+        }],
+        10 => &[ClassPowerGrant {
+            power: Power::Warrior(WarriorPower::WeaponMaster),
+            description: "Combat stunts cost you 1 less SP, to a minimum of 1.",
+            effect: Some(ClassPowerEffect::StuntCostReduction(StuntType::Combat, 1)),
+        }],
+        18 => &[ClassPowerGrant {
+            power: Power::Warrior(WarriorPower::LivingWeapon),
+            description: "Once per round, when you reduce an enemy to 0 Health, you may immediately make another attack.",
+            effect: None,
+        }],
+        _ => &[],
+    }
+}
 
 /// The initial selections the user must make for this class.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Level1Selections {
     pub weapon_groups: WeaponGroupSelection,
+    pub fighting_style: FightingStyleSelection,
+    pub veteran_stunt: VeteranStuntSelection,
 }
 
-/// This top-level advancement doesn't itself have any logic, but it has sub-advancements.
+/// This top-level advancement grants the Warrior's level 1 powers that don't involve
+/// any choices, along with the Armor Training talent. Sub-advancements cover the user's
+/// choices.
 impl Advancement for Level1Selections {
-    fn apply_self(&self, _: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        char.mechanical_properties.powers.warrior.armor_training = Some(ArmorTrainingDetails {});
+        char.mechanical_properties.talents.entry(Talent::ArmorTraining).or_insert(TalentDegree::Novice);
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.weapon_groups);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("weapon_groups", &self.weapon_groups);
+        f("fighting_style", &self.fighting_style);
+        f("veteran_stunt", &self.veteran_stunt);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
         f(&mut self.weapon_groups);
+        f(&mut self.fighting_style);
+        f(&mut self.veteran_stunt);
     }
 
     fn as_any(&self) -> &dyn std::any::Any { self }
@@ -84,3 +140,265 @@ impl InitialWeaponGroups for WeaponGroupSelection {
         &mut self.choices_
     }
 }
+
+/// The fighting style talents a Warrior can choose from at level 1.
+pub static FIGHTING_STYLES: [Talent; 7] = [
+    Talent::ArcheryStyle,
+    Talent::DualWeaponStyle,
+    Talent::SingleWeaponStyle,
+    Talent::ThrownWeaponStyle,
+    Talent::TwoHanderStyle,
+    Talent::UnarmedStyle,
+    Talent::WeaponAndShieldStyle,
+];
+
+/// The fighting style talent a Warrior starts with, at Novice degree.
+///
+/// Starting talents don't have to meet the talent's usual requirements.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FightingStyleSelection {
+    pub talent: Option<Talent>,
+}
+
+impl LeafNodeAdvancement for FightingStyleSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let talent = match self.talent {
+            None => return Ok(false),
+            Some(t) => t,
+        };
+        grant_starting_talent(char, talent, &FIGHTING_STYLES)?;
+        char.mechanical_properties.powers.warrior.fighting_style = Some(FightingStyleDetails { talent });
+        Ok(true)
+    }
+}
+
+/// The combat stunts a Warrior can choose from for the Veteran power.
+pub fn veteran_stunt_options() -> Vec<Stunt> {
+    Stunt::iter()
+        .filter(|stunt| stunt.stunt_type() == StuntType::Combat && stunt.classes().contains(&Class::Warrior))
+        .collect()
+}
+
+/// The extra combat stunt a Warrior starts with.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VeteranStuntSelection {
+    pub stunt: Option<Stunt>,
+}
+
+impl LeafNodeAdvancement for VeteranStuntSelection {
+    fn apply(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        let stunt = match self.stunt {
+            None => return Ok(false),
+            Some(s) => s,
+        };
+        if !stunt.classes().contains(&Class::Warrior) {
+            return Err(AdvancementError::StuntNotInClass { stunt, class: Class::Warrior });
+        }
+        if stunt.stunt_type() != StuntType::Combat {
+            return Err(AdvancementError::StuntNotCombat(stunt));
+        }
+        learn_stunt(char, stunt, StuntSource::Class)?;
+        char.mechanical_properties.powers.warrior.veteran = Some(VeteranDetails { stunt });
+        Ok(true)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// POWERS
+// -----------------------------------------------------------------------------
+
+/// Warrior-specific powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WarriorPower {
+    ArmorTraining,
+    FightingStyle,
+    Veteran,
+
+    // Powers gained after level 1.
+    Resilient,
+    BattleHardened,
+    WeaponMaster,
+    LivingWeapon,
+}
+
+impl std::fmt::Display for WarriorPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ArmorTraining => write!(f, "Armor Training (Warrior)"),
+            Self::FightingStyle => write!(f, "Fighting Style (Warrior)"),
+            Self::Veteran => write!(f, "Veteran (Warrior)"),
+            Self::Resilient => write!(f, "Resilient (Warrior)"),
+            Self::BattleHardened => write!(f, "Battle Hardened (Warrior)"),
+            Self::WeaponMaster => write!(f, "Weapon Master (Warrior)"),
+            Self::LivingWeapon => write!(f, "Living Weapon (Warrior)"),
+        }
+    }
+}
+
+/// Details about the various Warrior powers a character might have.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WarriorPowerDetails {
+    pub armor_training: Option<ArmorTrainingDetails>,
+    pub fighting_style: Option<FightingStyleDetails>,
+    pub veteran: Option<VeteranDetails>,
+
+    /// The powers gained automatically from leveling up, in the order they were gained.
+    pub level_powers: Vec<ClassPowerDetails>,
+}
+
+impl WarriorPowerDetails {
+    /// Iterate over the powers the character has.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PowerMechanics> {
+        [
+            into_generic_power_option(&self.armor_training),
+            into_generic_power_option(&self.fighting_style),
+            into_generic_power_option(&self.veteran),
+        ]
+            .into_iter()
+            .flatten()
+            .chain(self.level_powers.iter().map(|p| p as &dyn PowerMechanics))
+    }
+
+    /// Look up a power by ID.
+    pub fn lookup(&self, power: WarriorPower) -> Option<&dyn PowerMechanics> {
+        match power {
+            WarriorPower::ArmorTraining => into_generic_power_option(&self.armor_training),
+            WarriorPower::FightingStyle => into_generic_power_option(&self.fighting_style),
+            WarriorPower::Veteran => into_generic_power_option(&self.veteran),
+            // Powers gained after level 1 come from leveling up.
+            WarriorPower::Resilient |
+            WarriorPower::BattleHardened |
+            WarriorPower::WeaponMaster |
+            WarriorPower::LivingWeapon => self.level_powers.iter()
+                .find(|details| details.power == power.into())
+                .map(|details| details as &dyn PowerMechanics),
+        }
+    }
+}
+
+/// Metadata about the Armor Training power.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArmorTrainingDetails {
+    // There aren't actually any choices here, so there's nothing to store.
+}
+
+impl PowerMechanics for ArmorTrainingDetails {
+    fn power(&self) -> crate::Power {
+        WarriorPower::ArmorTraining.into()
+    }
+
+    fn name(&self) -> String {
+        "Armor Training (Warrior)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "You start with the Armor Training talent, and reduce your armor penalty by 1.".to_owned()
+    }
+
+    fn armor_penalty_reduction(&self) -> Option<i8> {
+        Some(1)
+    }
+}
+
+/// Metadata about the Fighting Style power.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FightingStyleDetails {
+    /// The fighting style talent the player chose.
+    pub talent: Talent,
+}
+
+impl PowerMechanics for FightingStyleDetails {
+    fn power(&self) -> crate::Power {
+        WarriorPower::FightingStyle.into()
+    }
+
+    fn name(&self) -> String {
+        "Fighting Style (Warrior)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("You start with the {} talent.", self.talent)
+    }
+}
+
+/// Metadata about the Veteran power.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VeteranDetails {
+    /// The combat stunt the player chose.
+    pub stunt: Stunt,
+}
+
+impl PowerMechanics for VeteranDetails {
+    fn power(&self) -> crate::Power {
+        WarriorPower::Veteran.into()
+    }
+
+    fn name(&self) -> String {
+        "Veteran (Warrior)".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("You start knowing the {} stunt.", self.stunt)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// SPECIALIZATIONS
+// -----------------------------------------------------------------------------
+
+/// Warrior-specific specializations.
+#[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WarriorSpecialization {
+    Berserker,
+    Guardian,
+    Knight,
+}
+
+impl WarriorSpecialization {
+    /// The display name for this specialization.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Berserker => "Berserker",
+            Self::Guardian => "Guardian",
+            Self::Knight => "Knight",
+        }
+    }
+
+    /// What a character needs before they can take this specialization.
+    pub fn requirements(&self) -> &'static [TalentRequirement] {
+        match self {
+            Self::Berserker => &[TalentRequirement::Ability(Ability::Constitution, 2)],
+            Self::Guardian => &[TalentRequirement::Ability(Ability::Strength, 2)],
+            Self::Knight => &[TalentRequirement::Focus(Focus::DexterityRiding)],
+        }
+    }
+
+    /// The benefit granted at the given degree of this specialization.
+    pub fn benefit(&self, degree: TalentDegree) -> &'static str {
+        match (self, degree) {
+            (Self::Berserker, TalentDegree::Novice) => "You can enter a battle rage, hitting harder but defending less.",
+            (Self::Berserker, TalentDegree::Expert) => "Your rage lets you shrug off wounds that would stop others.",
+            (Self::Berserker, TalentDegree::Master) => "Your rage makes you a terror on the battlefield.",
+            (Self::Guardian, TalentDegree::Novice) => "You can protect an adjacent ally from attacks.",
+            (Self::Guardian, TalentDegree::Expert) => "Enemies find it hard to get past you to your allies.",
+            (Self::Guardian, TalentDegree::Master) => "You can take a blow meant for an ally.",
+            (Self::Knight, TalentDegree::Novice) => "You fight well from the back of a horse.",
+            (Self::Knight, TalentDegree::Expert) => "Your mounted charges are devastating.",
+            (Self::Knight, TalentDegree::Master) => "You and your mount fight as one.",
+        }
+    }
+}
+
+impl std::fmt::Display for WarriorSpecialization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+//! Identifiers for pieces of equipment that can modify a character's stats.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Armor, Shield, Weapon};
+
+/// A piece of equipment.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Equipment {
+    Armor(Armor),
+    Shield(Shield),
+    Weapon(Weapon),
+}
+
+impl std::fmt::Display for Equipment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Armor(armor) => armor.fmt(f),
+            Self::Shield(shield) => shield.fmt(f),
+            Self::Weapon(weapon) => weapon.fmt(f),
+        }
+    }
+}
+
+impl From<Armor> for Equipment {
+    fn from(value: Armor) -> Self {
+        Equipment::Armor(value)
+    }
+}
+
+impl From<Shield> for Equipment {
+    fn from(value: Shield) -> Self {
+        Equipment::Shield(value)
+    }
+}
+
+impl From<Weapon> for Equipment {
+    fn from(value: Weapon) -> Self {
+        Equipment::Weapon(value)
+    }
+}
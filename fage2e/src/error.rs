@@ -0,0 +1,181 @@
+#![allow(dead_code)]
+//! Errors describing why an advancement couldn't be applied.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Ability, Arcana, Class, Focus, Specialization, Spell, Stunt, Talent, WeaponGroup};
+
+/// The reason an advancement couldn't be applied to a character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AdvancementError {
+    /// The advancement depends on the character's class, but none has been chosen yet.
+    NoClass,
+
+    /// A level was given for a character of a different class.
+    WrongClass { expected: Class, actual: Option<Class> },
+
+    /// The level number is outside the range this advancement handles.
+    LevelOutOfRange(u8),
+
+    /// The level doesn't directly follow the character's current level.
+    LevelOutOfOrder { expected: u8, actual: u8 },
+
+    /// A dice roll result is impossible for the dice being rolled.
+    RollOutOfRange { roll: i16, min: i16, max: i16 },
+
+    /// An ability was advanced past the maximum allowed at this point.
+    AbilityAboveMaximum { ability: Ability, max: i8 },
+
+    /// Two abilities' rolls were to be swapped, but both are the same ability.
+    SwapWithSelf(Ability),
+
+    /// A primary ability advancement was used on one of the class's secondary abilities.
+    AbilityNotPrimary { ability: Ability, class: Class },
+
+    /// A secondary ability advancement was used on one of the class's primary abilities.
+    AbilityNotSecondary { ability: Ability, class: Class },
+
+    /// A primary focus advancement was used on a focus for a secondary ability.
+    FocusNotPrimary { focus: Focus, class: Class },
+
+    /// A secondary focus advancement was used on a focus for a primary ability.
+    FocusNotSecondary { focus: Focus, class: Class },
+
+    /// The focus is already doubled and can't be advanced any further.
+    FocusAlreadyDoubled(Focus),
+
+    /// The focus is already taken, and focuses can't be doubled until level 11.
+    DoubleFocusBeforeLevel11(Focus),
+
+    /// The choice depends on the character's Wildfolk species, but none has been chosen yet.
+    NoSpecies,
+
+    /// The focus isn't one of the available choices.
+    FocusNotAvailable(Focus),
+
+    /// The focus is already known, and this choice can't double it.
+    FocusAlreadyKnown(Focus),
+
+    /// The weapon group isn't one of the class's choices.
+    WeaponGroupNotSelectable(WeaponGroup),
+
+    /// The same ancestry benefit was selected twice.
+    DuplicateAncestryBenefit(String),
+
+    /// A second ancestry benefit was selected alongside one that counts as two.
+    BenefitCountsAsTwo(String),
+
+    /// The same arcana was selected twice.
+    DuplicateArcana(Arcana),
+
+    /// The spell's arcana isn't known.
+    ArcanaNotKnown { spell: Spell, arcana: Arcana },
+
+    /// The spell is already known.
+    SpellAlreadyKnown(Spell),
+
+    /// The spell's degree is too high for the character's level.
+    SpellAboveLevel { spell: Spell, min_level: u8 },
+
+    /// The stunt can only be granted by an ancestry.
+    AncestryStunt(Stunt),
+
+    /// The stunt isn't one of the class's stunts.
+    StuntNotInClass { stunt: Stunt, class: Class },
+
+    /// The stunt is already known.
+    StuntAlreadyKnown(Stunt),
+
+    /// Only a combat stunt can be chosen here.
+    StuntNotCombat(Stunt),
+
+    /// The talent's class restrictions or requirements aren't met.
+    TalentNotAvailable(Talent),
+
+    /// The talent is already at Master degree.
+    TalentAlreadyMastered(Talent),
+
+    /// The specialization belongs to a different class.
+    SpecializationWrongClass { specialization: Specialization, class: Option<Class> },
+
+    /// The specialization's requirements aren't met.
+    SpecializationRequirementsNotMet(Specialization),
+
+    /// The specialization is already at Master degree.
+    SpecializationAlreadyMastered(Specialization),
+
+    /// A new specialization can't be started until the current one is mastered.
+    SpecializationNotMastered(Specialization),
+
+    /// Both a specialization and a talent were chosen, but only one is allowed.
+    SpecializationAndTalent,
+}
+
+impl std::fmt::Display for AdvancementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoClass => write!(f, "A class must be chosen first"),
+            Self::WrongClass { expected, actual: Some(actual) } =>
+                write!(f, "This level is for a {}, but the character is a {}", expected, actual),
+            Self::WrongClass { expected, actual: None } =>
+                write!(f, "This level is for a {}, but the character has no class", expected),
+            Self::LevelOutOfRange(level) => write!(f, "Level {} is out of range", level),
+            Self::LevelOutOfOrder { expected, actual } =>
+                write!(f, "Expected level {}, but got level {}", expected, actual),
+            Self::RollOutOfRange { roll, min, max } =>
+                write!(f, "A roll of {} is outside the possible range of {} to {}", roll, min, max),
+            Self::AbilityAboveMaximum { ability, max } =>
+                write!(f, "{} can't be advanced past {}", ability, max),
+            Self::SwapWithSelf(ability) => write!(f, "Can't swap {} with itself", ability),
+            Self::AbilityNotPrimary { ability, class } =>
+                write!(f, "{} isn't a primary ability for a {}", ability, class),
+            Self::AbilityNotSecondary { ability, class } =>
+                write!(f, "{} isn't a secondary ability for a {}", ability, class),
+            Self::FocusNotPrimary { focus, class } =>
+                write!(f, "{} isn't a focus in a primary ability for a {}", focus, class),
+            Self::FocusNotSecondary { focus, class } =>
+                write!(f, "{} isn't a focus in a secondary ability for a {}", focus, class),
+            Self::FocusAlreadyDoubled(focus) => write!(f, "{} is already a double focus", focus),
+            Self::DoubleFocusBeforeLevel11(focus) =>
+                write!(f, "{} is already a focus, and focuses can't be doubled until level 11", focus),
+            Self::NoSpecies => write!(f, "A Wildfolk species must be chosen first"),
+            Self::FocusNotAvailable(focus) => write!(f, "{} isn't one of the available focuses", focus),
+            Self::FocusAlreadyKnown(focus) => write!(f, "{} is already a focus", focus),
+            Self::WeaponGroupNotSelectable(group) =>
+                write!(f, "{} isn't one of the available weapon groups", group),
+            Self::DuplicateAncestryBenefit(benefit) => write!(f, "{} was selected twice", benefit),
+            Self::BenefitCountsAsTwo(benefit) =>
+                write!(f, "{} counts as two benefits, so no other benefit can be selected", benefit),
+            Self::DuplicateArcana(arcana) => write!(f, "{} was selected twice", arcana),
+            Self::ArcanaNotKnown { spell, arcana } =>
+                write!(f, "{} requires knowing the {}", spell, arcana),
+            Self::SpellAlreadyKnown(spell) => write!(f, "{} is already known", spell),
+            Self::SpellAboveLevel { spell, min_level } =>
+                write!(f, "{} can't be learned until level {}", spell, min_level),
+            Self::AncestryStunt(stunt) => write!(f, "{} can only be gained from an ancestry", stunt),
+            Self::StuntNotInClass { stunt, class } =>
+                write!(f, "{} isn't a {} stunt", stunt, class),
+            Self::StuntAlreadyKnown(stunt) => write!(f, "{} is already known", stunt),
+            Self::StuntNotCombat(stunt) => write!(f, "{} isn't a combat stunt", stunt),
+            Self::TalentNotAvailable(talent) =>
+                write!(f, "The requirements for {} aren't met", talent),
+            Self::TalentAlreadyMastered(talent) => write!(f, "{} is already mastered", talent),
+            Self::SpecializationWrongClass { specialization, class: Some(class) } =>
+                write!(f, "{} isn't a {} specialization", specialization, class),
+            Self::SpecializationWrongClass { specialization, class: None } =>
+                write!(f, "{} requires the {} class", specialization, specialization.class()),
+            Self::SpecializationRequirementsNotMet(specialization) =>
+                write!(f, "The requirements for {} aren't met", specialization),
+            Self::SpecializationAlreadyMastered(specialization) =>
+                write!(f, "{} is already mastered", specialization),
+            Self::SpecializationNotMastered(specialization) =>
+                write!(f, "{} must be mastered before starting another specialization", specialization),
+            Self::SpecializationAndTalent =>
+                write!(f, "Choose either a specialization or a talent, not both"),
+        }
+    }
+}
+
+impl std::error::Error for AdvancementError {}
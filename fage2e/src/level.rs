@@ -3,9 +3,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{draak, dwarf, elf, gnome, goblin, halfling, human, orc, wildfolk, Advancement, Ancestry, Character, Class, DiceBasedHealthAdvancement};
+use crate::{draak, dwarf, elf, gnome, goblin, halfling, human, orc, wildfolk, Advancement, AdvancementError, Ancestry, Character, Class, ClassPowerDetails, DiceBasedHealthAdvancement};
 use crate::{envoy, mage, rogue, warrior};
 use crate::{AbilityDetermination, SelectName};
+use crate::{AbilityAdvancement, DefenseAdvancement, FocusAdvancement, HealthAdvancement, MagicPointsAdvancement};
+use crate::{OutOfClassStuntAdvancement, SpecializationAdvancement, SpellAdvancement, StuntAdvancement, TalentAdvancement};
 
 /// All Level 1 advancements.
 #[derive(Debug, Clone, Default)]
@@ -38,17 +40,17 @@ pub struct Level1 {
 /// The "Advancement" part of Level 1 just sets the player's level.
 /// The real meat and potatoes are in the sub-advancements.
 impl Advancement for Level1 {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.level = 1;
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
-        f(&self.name);
-        f(&self.abilities);
-        f(&self.class);
-        f(&self.ancestry);
-        f(&self.health);
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("name", &self.name);
+        f("abilities", &self.abilities);
+        f("class", &self.class);
+        f("ancestry", &self.ancestry);
+        f("health", &self.health);
     }
 
     fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
@@ -78,7 +80,7 @@ pub enum Level1ClassSelections {
 /// The "Advancement" part of Class Selection just sets the character's class.
 /// Class-specific selections are sub-advancements.
 impl Advancement for Level1ClassSelections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.class = Some(match self {
             Self::NoChoice => return Ok(false),
             Self::Envoy(_) => Class::Envoy,
@@ -89,13 +91,13 @@ impl Advancement for Level1ClassSelections {
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
         match self {
             Self::NoChoice => (),
-            Self::Envoy(sub) => f(sub),
-            Self::Mage(sub) => f(sub),
-            Self::Rogue(sub) => f(sub),
-            Self::Warrior(sub) => f(sub),
+            Self::Envoy(sub) => f("envoy", sub),
+            Self::Mage(sub) => f("mage", sub),
+            Self::Rogue(sub) => f("rogue", sub),
+            Self::Warrior(sub) => f("warrior", sub),
         }
     }
 
@@ -133,7 +135,7 @@ pub enum Level1AncestrySelections {
 /// The "Advancement" part of Ancestry Selection just sets the character's ancestry.
 /// Ancestry-specific selections are sub-advancements.
 impl Advancement for Level1AncestrySelections {
-    fn apply_self(&self, char: &mut Character) -> Result<bool, ()> {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
         char.mechanical_properties.ancestry = Some(match self {
             Self::NoChoice => return Ok(false),
             Self::Draak(_) => Ancestry::Draak,
@@ -149,18 +151,18 @@ impl Advancement for Level1AncestrySelections {
         Ok(true)
     }
 
-    fn foreach(&self, f: &mut dyn FnMut(&dyn Advancement)) {
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
         match self {
             Self::NoChoice => (),
-            Self::Draak(sub) => f(sub),
-            Self::Dwarf(sub) => f(sub),
-            Self::Elf(sub) => f(sub),
-            Self::Gnome(sub) => f(sub),
-            Self::Goblin(sub) => f(sub),
-            Self::Halfling(sub) => f(sub),
-            Self::Human(sub) => f(sub),
-            Self::Orc(sub) => f(sub),
-            Self::Wildfolk(sub) => f(sub),
+            Self::Draak(sub) => f("draak", sub),
+            Self::Dwarf(sub) => f("dwarf", sub),
+            Self::Elf(sub) => f("elf", sub),
+            Self::Gnome(sub) => f("gnome", sub),
+            Self::Goblin(sub) => f("goblin", sub),
+            Self::Halfling(sub) => f("halfling", sub),
+            Self::Human(sub) => f("human", sub),
+            Self::Orc(sub) => f("orc", sub),
+            Self::Wildfolk(sub) => f("wildfolk", sub),
         }
     }
 
@@ -181,3 +183,318 @@ impl Advancement for Level1AncestrySelections {
 
     fn as_any(&self) -> &dyn std::any::Any { self }
 }
+
+
+/// All advancements for a single level from 2 through 20.
+///
+/// Which advancements are available depends on the character's class, so this is built
+/// for a specific class. If the character's class changes, this needs to be rebuilt.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LevelN {
+    /// The level this advances the character to.
+    pub level: u8,
+
+    /// The class this level's advancements were laid out for.
+    pub class: Class,
+
+    pub ability: AbilityAdvancement,
+    pub focus: FocusAdvancement,
+    pub health: HealthAdvancement,
+
+    // These are only available at certain levels, depending on class.
+    pub magic_points: Option<MagicPointsAdvancement>,
+    pub defense: Option<DefenseAdvancement>,
+    pub talent: Option<TalentAdvancement>,
+    pub specialization: Option<SpecializationAdvancement>,
+    pub stunt: Option<StuntAdvancement>,
+    pub out_of_class_stunt: Option<OutOfClassStuntAdvancement>,
+    pub spell: Option<SpellAdvancement>,
+}
+
+impl LevelN {
+    /// The first level this kind of advancement applies to.
+    pub const MIN_LEVEL: u8 = 2;
+
+    /// The last level this kind of advancement applies to.
+    pub const MAX_LEVEL: u8 = 20;
+
+    /// Lay out the advancements for the given level and class.
+    pub fn new(level: u8, class: Class) -> Self {
+        let available = class.level_advancements(level);
+        Self {
+            level,
+            class,
+            ability: AbilityAdvancement::for_level(level),
+            focus: FocusAdvancement::for_level(level),
+            health: HealthAdvancement::for_level(level),
+            magic_points: available.magic_points.then(MagicPointsAdvancement::default),
+            defense: available.defense.then(DefenseAdvancement::default),
+            talent: available.talent.then(TalentAdvancement::default),
+            specialization: available.specialization.then(SpecializationAdvancement::default),
+            stunt: available.stunt.then(StuntAdvancement::default),
+            out_of_class_stunt: available.out_of_class_stunt.then(OutOfClassStuntAdvancement::default),
+            spell: available.spell.then(SpellAdvancement::default),
+        }
+    }
+}
+
+/// The "Advancement" part of a level sets the player's level, after checking that it
+/// follows the previous level and matches the character's class, and grants the powers
+/// the class gains at that level. The real meat and potatoes are in the sub-advancements.
+impl Advancement for LevelN {
+    fn apply_self(&self, char: &mut Character) -> Result<bool, AdvancementError> {
+        if !(Self::MIN_LEVEL..=Self::MAX_LEVEL).contains(&self.level) {
+            return Err(AdvancementError::LevelOutOfRange(self.level));
+        }
+        if char.mechanical_properties.level + 1 != self.level {
+            return Err(AdvancementError::LevelOutOfOrder {
+                expected: char.mechanical_properties.level + 1,
+                actual: self.level,
+            });
+        }
+        if char.mechanical_properties.class != Some(self.class) {
+            return Err(AdvancementError::WrongClass { expected: self.class, actual: char.mechanical_properties.class });
+        }
+        char.mechanical_properties.level = self.level;
+        let powers = &mut char.mechanical_properties.powers;
+        let level_powers = match self.class {
+            Class::Envoy => &mut powers.envoy.level_powers,
+            Class::Mage => &mut powers.mage.level_powers,
+            Class::Rogue => &mut powers.rogue.level_powers,
+            Class::Warrior => &mut powers.warrior.level_powers,
+        };
+        level_powers.extend(
+            self.class.powers_gained_at(self.level).iter()
+                .map(|grant| ClassPowerDetails { power: grant.power, level: self.level })
+        );
+        Ok(true)
+    }
+
+    fn foreach(&self, f: &mut dyn FnMut(&str, &dyn Advancement)) {
+        f("ability", &self.ability);
+        f("focus", &self.focus);
+        f("health", &self.health);
+        if let Some(magic_points) = &self.magic_points { f("magic_points", magic_points); }
+        if let Some(defense) = &self.defense { f("defense", defense); }
+        if let Some(talent) = &self.talent { f("talent", talent); }
+        if let Some(specialization) = &self.specialization { f("specialization", specialization); }
+        if let Some(stunt) = &self.stunt { f("stunt", stunt); }
+        if let Some(stunt) = &self.out_of_class_stunt { f("out_of_class_stunt", stunt); }
+        if let Some(spell) = &self.spell { f("spell", spell); }
+    }
+
+    fn foreach_mut(&mut self, f: &mut dyn FnMut(&mut dyn Advancement)) {
+        f(&mut self.ability);
+        f(&mut self.focus);
+        f(&mut self.health);
+        if let Some(magic_points) = &mut self.magic_points { f(magic_points); }
+        if let Some(defense) = &mut self.defense { f(defense); }
+        if let Some(talent) = &mut self.talent { f(talent); }
+        if let Some(specialization) = &mut self.specialization { f(specialization); }
+        if let Some(stunt) = &mut self.stunt { f(stunt); }
+        if let Some(stunt) = &mut self.out_of_class_stunt { f(stunt); }
+        if let Some(spell) = &mut self.spell { f(spell); }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::{Ability, AncestryBenefitSelections, Arcana, Focus, ManuallyEnterAbilities, Specialization, Spell, Stunt, Talent, WeaponGroup};
+
+    /// A Level 1 Elf of the given class, with every choice filled out.
+    ///
+    /// Constitution and Willpower are 2 and Dexterity is 1, and every roll is a 3, so
+    /// each level's health and magic points are easy to work out by hand.
+    fn level1(class: Level1ClassSelections) -> Level1 {
+        Level1 {
+            name: SelectName { name: "Test".to_string() },
+            abilities: AbilityDetermination::Manual(ManuallyEnterAbilities {
+                accuracy: 1,
+                communication: 1,
+                constitution: 2,
+                dexterity: 1,
+                fighting: 1,
+                intelligence: 1,
+                perception: 1,
+                strength: 1,
+                willpower: 2,
+            }),
+            class,
+            ancestry: Level1AncestrySelections::Elf(elf::Level1Selections {
+                ability_focus: Some(elf::AbilityFocusSelection::Seeing),
+                benefits: AncestryBenefitSelections {
+                    selection1: Some(elf::ElfBenefit::AccuracyBows),
+                    selection2: Some(elf::ElfBenefit::PerceptionHearing),
+                    selections_were_rolled: false,
+                },
+            }),
+            health: DiceBasedHealthAdvancement { roll_result: Some(3) },
+        }
+    }
+
+    fn envoy() -> Level1ClassSelections {
+        Level1ClassSelections::Envoy(envoy::Level1Selections {
+            weapon_groups: envoy::WeaponGroupSelection {
+                choices_: [Some(WeaponGroup::BlackPowder), Some(WeaponGroup::Bludgeons), Some(WeaponGroup::Bows)],
+            },
+            starting_talent: envoy::StartingTalentSelection { talent: Some(envoy::STARTING_TALENTS[0]) },
+            starting_specialization: envoy::StartingSpecializationSelection {
+                specialization: Some(envoy::EnvoySpecialization::Agent),
+            },
+        })
+    }
+
+    fn mage() -> Level1ClassSelections {
+        Level1ClassSelections::Mage(mage::Level1Selections {
+            weapon_groups: mage::WeaponGroupSelection { choices_: [] },
+            arcana: mage::ArcanaSelection { choices: [Some(Arcana::Fire), Some(Arcana::Water)] },
+            magic_points: MagicPointsAdvancement { roll_result: Some(3) },
+        })
+    }
+
+    fn rogue() -> Level1ClassSelections {
+        Level1ClassSelections::Rogue(rogue::Level1Selections {
+            weapon_groups: rogue::WeaponGroupSelection {
+                choices_: [Some(WeaponGroup::BlackPowder), Some(WeaponGroup::Bows)],
+            },
+            starting_talent: rogue::StartingTalentSelection { talent: Some(rogue::STARTING_TALENTS[0]) },
+        })
+    }
+
+    fn warrior() -> Level1ClassSelections {
+        Level1ClassSelections::Warrior(warrior::Level1Selections {
+            weapon_groups: warrior::WeaponGroupSelection {
+                choices_: [
+                    Some(WeaponGroup::Axes),
+                    Some(WeaponGroup::BlackPowder),
+                    Some(WeaponGroup::Bludgeons),
+                    Some(WeaponGroup::Bows),
+                ],
+            },
+            fighting_style: warrior::FightingStyleSelection { talent: Some(warrior::FIGHTING_STYLES[0]) },
+            veteran_stunt: warrior::VeteranStuntSelection { stunt: warrior::veteran_stunt_options().first().copied() },
+        })
+    }
+
+    /// Pick the first option that applies cleanly to the character, and apply it.
+    fn first_valid<A: Advancement>(char: &mut Character, options: impl IntoIterator<Item = A>) -> A {
+        for option in options {
+            let mut trial = char.clone();
+            if let Ok(true) = option.apply_all(&mut trial) {
+                *char = trial;
+                return option;
+            }
+        }
+        panic!("No valid option at level {}", char.mechanical_properties.level);
+    }
+
+    /// Fill out every advancement for the given level, rolling 3 wherever there's a roll.
+    ///
+    /// Abilities that feed into health, defense and magic points are never advanced.
+    fn level_n(char: &Character, level: u8, class: Class) -> LevelN {
+        let mut adv = LevelN::new(level, class);
+        let mut scratch = char.clone();
+        adv.apply_self(&mut scratch).unwrap();
+
+        let abilities = Ability::iter()
+            .filter(|ability| ![Ability::Constitution, Ability::Dexterity, Ability::Willpower].contains(ability));
+        adv.ability = first_valid(&mut scratch, abilities.map(|ability| {
+            let mut ability_adv = AbilityAdvancement::for_level(level);
+            *ability_adv.ability_mut() = Some(ability);
+            ability_adv
+        }));
+        adv.focus = first_valid(&mut scratch, Focus::iter().map(|focus| {
+            let mut focus_adv = FocusAdvancement::for_level(level);
+            *focus_adv.focus_mut() = Some(focus);
+            focus_adv
+        }));
+        if let HealthAdvancement::Dice(health) = &mut adv.health {
+            health.roll_result = Some(3);
+        }
+        adv.health.apply_all(&mut scratch).unwrap();
+        if let Some(magic_points) = &mut adv.magic_points {
+            magic_points.roll_result = Some(3);
+            magic_points.apply_all(&mut scratch).unwrap();
+        }
+        if let Some(defense) = &adv.defense {
+            defense.apply_all(&mut scratch).unwrap();
+        }
+        if adv.talent.is_some() {
+            adv.talent = Some(first_valid(&mut scratch, Talent::iter().map(|talent| TalentAdvancement { talent: Some(talent) })));
+        }
+        if adv.specialization.is_some() {
+            let specializations = Specialization::for_class(class).into_iter()
+                .map(|specialization| SpecializationAdvancement { specialization: Some(specialization), talent: None });
+            let talents = Talent::iter()
+                .map(|talent| SpecializationAdvancement { specialization: None, talent: Some(talent) });
+            adv.specialization = Some(first_valid(&mut scratch, specializations.chain(talents)));
+        }
+        if adv.stunt.is_some() {
+            adv.stunt = Some(first_valid(&mut scratch, Stunt::iter().map(|stunt| StuntAdvancement { stunt: Some(stunt) })));
+        }
+        if adv.out_of_class_stunt.is_some() {
+            adv.out_of_class_stunt = Some(first_valid(&mut scratch, Stunt::iter().map(|stunt| OutOfClassStuntAdvancement { stunt: Some(stunt) })));
+        }
+        if adv.spell.is_some() {
+            adv.spell = Some(first_valid(&mut scratch, Spell::iter().map(|spell| SpellAdvancement { spell: Some(spell) })));
+        }
+        adv
+    }
+
+    /// Build a character of the given class from level 1 all the way to level 20.
+    fn level_to_20(class_selections: Level1ClassSelections, class: Class) -> Character {
+        let mut char = Character::new();
+        assert_eq!(level1(class_selections).apply_all(&mut char), Ok(true));
+        for level in LevelN::MIN_LEVEL..=LevelN::MAX_LEVEL {
+            let adv = level_n(&char, level, class);
+            assert_eq!(adv.apply_all(&mut char), Ok(true), "level {}", level);
+        }
+        char
+    }
+
+    // Health is the class's starting health, plus Constitution + 3 at levels 1 through 10,
+    // plus Constitution at levels 11 through 20: 10 * (2 + 3) + 10 * 2 = 70.
+
+    #[test]
+    fn envoy_levels_1_to_20() {
+        let char = level_to_20(envoy(), Class::Envoy);
+        assert_eq!(char.mechanical_properties.level, 20);
+        assert_eq!(char.max_health().final_value(), 25 + 70);
+        assert_eq!(char.defense().final_value(), 10 + 1 + 2);
+        assert_eq!(char.max_magic_points().final_value(), 0);
+    }
+
+    #[test]
+    fn mage_levels_1_to_20() {
+        let char = level_to_20(mage(), Class::Mage);
+        assert_eq!(char.mechanical_properties.level, 20);
+        assert_eq!(char.max_health().final_value(), 20 + 70);
+        assert_eq!(char.defense().final_value(), 10 + 1 + 2);
+        // Willpower + 3 at every level.
+        assert_eq!(char.max_magic_points().final_value(), 10 + 20 * (2 + 3));
+    }
+
+    #[test]
+    fn rogue_levels_1_to_20() {
+        let char = level_to_20(rogue(), Class::Rogue);
+        assert_eq!(char.mechanical_properties.level, 20);
+        assert_eq!(char.max_health().final_value(), 25 + 70);
+        assert_eq!(char.defense().final_value(), 10 + 1 + 4);
+        assert_eq!(char.max_magic_points().final_value(), 0);
+    }
+
+    #[test]
+    fn warrior_levels_1_to_20() {
+        let char = level_to_20(warrior(), Class::Warrior);
+        assert_eq!(char.mechanical_properties.level, 20);
+        assert_eq!(char.max_health().final_value(), 30 + 70);
+        assert_eq!(char.defense().final_value(), 10 + 1 + 4);
+        assert_eq!(char.max_magic_points().final_value(), 0);
+    }
+}
@@ -1,23 +1,41 @@
 mod ability;
 mod advancement;
 mod ancestry;
+mod armor;
 mod character;
 mod character_creation;
 mod class;
+mod equipment;
+mod error;
 mod level;
+mod magic;
 mod modifier;
 mod numbers;
 mod power;
+mod shield;
+mod specialization;
+mod stunt;
+mod talent;
+mod validation;
 mod weapon;
 
 pub use ability::*;
 pub use advancement::*;
 pub use ancestry::*;
+pub use armor::*;
 pub use character::*;
 pub use character_creation::*;
 pub use class::*;
+pub use equipment::*;
+pub use error::*;
 pub use level::*;
+pub use magic::*;
 pub use modifier::*;
 pub use numbers::*;
 pub use power::*;
+pub use shield::*;
+pub use specialization::*;
+pub use stunt::*;
+pub use talent::*;
+pub use validation::*;
 pub use weapon::*;
@@ -0,0 +1,626 @@
+#![allow(dead_code)]
+//! Arcana, spells, and the things mages need to cast them.
+
+use strum::{EnumIter, IntoEnumIterator};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Focus, TalentDegree};
+
+/// A school of magic.
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Arcana {
+    Air,
+    Divination,
+    Earth,
+    Fire,
+    Healing,
+    Heroic,
+    Lightning,
+    Power,
+    Shadow,
+    Water,
+}
+
+impl Arcana {
+    pub fn spells(self) -> impl Iterator<Item = Spell> {
+        Spell::iter().filter(move |s| s.arcana() == self)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Air => "Air Arcana",
+            Self::Divination => "Divination Arcana",
+            Self::Earth => "Earth Arcana",
+            Self::Fire => "Fire Arcana",
+            Self::Healing => "Healing Arcana",
+            Self::Heroic => "Heroic Arcana",
+            Self::Lightning => "Lightning Arcana",
+            Self::Power => "Power Arcana",
+            Self::Shadow => "Shadow Arcana",
+            Self::Water => "Water Arcana",
+        }
+    }
+
+    /// The focus used when casting spells from this arcana.
+    pub fn focus(&self) -> Focus {
+        match self {
+            Self::Air => Focus::IntelligenceAirArcana,
+            Self::Divination => Focus::IntelligenceDivinationArcana,
+            Self::Earth => Focus::IntelligenceEarthArcana,
+            Self::Fire => Focus::IntelligenceFireArcana,
+            Self::Healing => Focus::IntelligenceHealingArcana,
+            Self::Heroic => Focus::IntelligenceHeroicArcana,
+            Self::Lightning => Focus::IntelligenceLightningArcana,
+            Self::Power => Focus::IntelligencePowerArcana,
+            Self::Shadow => Focus::IntelligenceShadowArcana,
+            Self::Water => Focus::IntelligenceWaterArcana,
+        }
+    }
+}
+
+impl std::fmt::Display for Arcana {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How long it takes to cast a spell.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CastingTime {
+    MinorAction,
+    MajorAction,
+}
+
+impl std::fmt::Display for CastingTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinorAction => write!(f, "Minor Action"),
+            Self::MajorAction => write!(f, "Major Action"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Spell {
+    // AIR
+    WindBlast,
+    Updraft,
+    Whirlwind,
+    Tempest,
+
+    // DIVINATION
+    SenseMagic,
+    Foresight,
+    TrueSight,
+    Prophecy,
+
+    // EARTH
+    Stoneskin,
+    Tremor,
+    EarthenGrasp,
+    Earthquake,
+
+    // FIRE
+    FlameBlast,
+    FireShield,
+    Fireball,
+    Inferno,
+
+    // HEALING
+    HealingTouch,
+    Purify,
+    MassHealing,
+    Restoration,
+
+    // HEROIC
+    Bolster,
+    HeroicInspiration,
+    ChampionsAura,
+    LegendaryValor,
+
+    // LIGHTNING
+    Jolt,
+    LightningLash,
+    ChainLightning,
+    Thunderstorm,
+
+    // POWER
+    ArcaneShield,
+    ForcePush,
+    ArcaneCage,
+    Disintegrate,
+
+    // SHADOW
+    ShadowCloak,
+    Dread,
+    ShadowWalk,
+    Nightfall,
+
+    // WATER
+    WaterBreathing,
+    WaterWhip,
+    Drown,
+    TidalWave,
+}
+
+impl Spell {
+    pub fn arcana(&self) -> Arcana {
+        match self {
+            // AIR
+            Self::WindBlast |
+            Self::Updraft |
+            Self::Whirlwind |
+            Self::Tempest => Arcana::Air,
+
+            // DIVINATION
+            Self::SenseMagic |
+            Self::Foresight |
+            Self::TrueSight |
+            Self::Prophecy => Arcana::Divination,
+
+            // EARTH
+            Self::Stoneskin |
+            Self::Tremor |
+            Self::EarthenGrasp |
+            Self::Earthquake => Arcana::Earth,
+
+            // FIRE
+            Self::FlameBlast |
+            Self::FireShield |
+            Self::Fireball |
+            Self::Inferno => Arcana::Fire,
+
+            // HEALING
+            Self::HealingTouch |
+            Self::Purify |
+            Self::MassHealing |
+            Self::Restoration => Arcana::Healing,
+
+            // HEROIC
+            Self::Bolster |
+            Self::HeroicInspiration |
+            Self::ChampionsAura |
+            Self::LegendaryValor => Arcana::Heroic,
+
+            // LIGHTNING
+            Self::Jolt |
+            Self::LightningLash |
+            Self::ChainLightning |
+            Self::Thunderstorm => Arcana::Lightning,
+
+            // POWER
+            Self::ArcaneShield |
+            Self::ForcePush |
+            Self::ArcaneCage |
+            Self::Disintegrate => Arcana::Power,
+
+            // SHADOW
+            Self::ShadowCloak |
+            Self::Dread |
+            Self::ShadowWalk |
+            Self::Nightfall => Arcana::Shadow,
+
+            // WATER
+            Self::WaterBreathing |
+            Self::WaterWhip |
+            Self::Drown |
+            Self::TidalWave => Arcana::Water,
+        }
+    }
+
+    /// The degree of mastery in the arcana needed to learn this spell.
+    ///
+    /// Novice spells are learned along with the arcana itself; anything beyond that has
+    /// to be learned separately.
+    pub fn degree(&self) -> TalentDegree {
+        match self {
+            Self::WindBlast => TalentDegree::Novice,
+            Self::Updraft => TalentDegree::Novice,
+            Self::Whirlwind => TalentDegree::Expert,
+            Self::Tempest => TalentDegree::Master,
+            Self::SenseMagic => TalentDegree::Novice,
+            Self::Foresight => TalentDegree::Novice,
+            Self::TrueSight => TalentDegree::Expert,
+            Self::Prophecy => TalentDegree::Master,
+            Self::Stoneskin => TalentDegree::Novice,
+            Self::Tremor => TalentDegree::Novice,
+            Self::EarthenGrasp => TalentDegree::Expert,
+            Self::Earthquake => TalentDegree::Master,
+            Self::FlameBlast => TalentDegree::Novice,
+            Self::FireShield => TalentDegree::Novice,
+            Self::Fireball => TalentDegree::Expert,
+            Self::Inferno => TalentDegree::Master,
+            Self::HealingTouch => TalentDegree::Novice,
+            Self::Purify => TalentDegree::Novice,
+            Self::MassHealing => TalentDegree::Expert,
+            Self::Restoration => TalentDegree::Master,
+            Self::Bolster => TalentDegree::Novice,
+            Self::HeroicInspiration => TalentDegree::Novice,
+            Self::ChampionsAura => TalentDegree::Expert,
+            Self::LegendaryValor => TalentDegree::Master,
+            Self::Jolt => TalentDegree::Novice,
+            Self::LightningLash => TalentDegree::Novice,
+            Self::ChainLightning => TalentDegree::Expert,
+            Self::Thunderstorm => TalentDegree::Master,
+            Self::ArcaneShield => TalentDegree::Novice,
+            Self::ForcePush => TalentDegree::Novice,
+            Self::ArcaneCage => TalentDegree::Expert,
+            Self::Disintegrate => TalentDegree::Master,
+            Self::ShadowCloak => TalentDegree::Novice,
+            Self::Dread => TalentDegree::Novice,
+            Self::ShadowWalk => TalentDegree::Expert,
+            Self::Nightfall => TalentDegree::Master,
+            Self::WaterBreathing => TalentDegree::Novice,
+            Self::WaterWhip => TalentDegree::Novice,
+            Self::Drown => TalentDegree::Expert,
+            Self::TidalWave => TalentDegree::Master,
+        }
+    }
+
+    /// The lowest character level at which this spell can be learned.
+    ///
+    /// Expert spells open up partway through a mage's career, and Master spells only once
+    /// they're allowed to double focus.
+    pub fn min_level(&self) -> u8 {
+        match self.degree() {
+            TalentDegree::Novice => 1,
+            TalentDegree::Expert => 6,
+            TalentDegree::Master => 11,
+        }
+    }
+
+    pub fn properties(&self) -> SpellProperties {
+        match self {
+            // AIR
+            Self::WindBlast => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: Some(Focus::StrengthMight),
+            },
+            Self::Updraft => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MinorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::Whirlwind => SpellProperties {
+                mp_cost: 6,
+                casting_time: CastingTime::MajorAction,
+                target_number: 13,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+            Self::Tempest => SpellProperties {
+                mp_cost: 9,
+                casting_time: CastingTime::MajorAction,
+                target_number: 15,
+                test: Some(Focus::StrengthMight),
+            },
+
+            // DIVINATION
+            Self::SenseMagic => SpellProperties {
+                mp_cost: 1,
+                casting_time: CastingTime::MinorAction,
+                target_number: 9,
+                test: None,
+            },
+            Self::Foresight => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MajorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::TrueSight => SpellProperties {
+                mp_cost: 5,
+                casting_time: CastingTime::MajorAction,
+                target_number: 13,
+                test: None,
+            },
+            Self::Prophecy => SpellProperties {
+                mp_cost: 8,
+                casting_time: CastingTime::MajorAction,
+                target_number: 15,
+                test: None,
+            },
+
+            // EARTH
+            Self::Stoneskin => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: None,
+            },
+            Self::Tremor => SpellProperties {
+                mp_cost: 4,
+                casting_time: CastingTime::MajorAction,
+                target_number: 12,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+            Self::EarthenGrasp => SpellProperties {
+                mp_cost: 6,
+                casting_time: CastingTime::MajorAction,
+                target_number: 13,
+                test: Some(Focus::StrengthMight),
+            },
+            Self::Earthquake => SpellProperties {
+                mp_cost: 9,
+                casting_time: CastingTime::MajorAction,
+                target_number: 15,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+
+            // FIRE
+            Self::FlameBlast => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+            Self::FireShield => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MinorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::Fireball => SpellProperties {
+                mp_cost: 7,
+                casting_time: CastingTime::MajorAction,
+                target_number: 14,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+            Self::Inferno => SpellProperties {
+                mp_cost: 10,
+                casting_time: CastingTime::MajorAction,
+                target_number: 16,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+
+            // HEALING
+            Self::HealingTouch => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MajorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::Purify => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: None,
+            },
+            Self::MassHealing => SpellProperties {
+                mp_cost: 8,
+                casting_time: CastingTime::MajorAction,
+                target_number: 14,
+                test: None,
+            },
+            Self::Restoration => SpellProperties {
+                mp_cost: 9,
+                casting_time: CastingTime::MajorAction,
+                target_number: 15,
+                test: None,
+            },
+
+            // HEROIC
+            Self::Bolster => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MinorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::HeroicInspiration => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: None,
+            },
+            Self::ChampionsAura => SpellProperties {
+                mp_cost: 6,
+                casting_time: CastingTime::MajorAction,
+                target_number: 13,
+                test: None,
+            },
+            Self::LegendaryValor => SpellProperties {
+                mp_cost: 8,
+                casting_time: CastingTime::MajorAction,
+                target_number: 15,
+                test: None,
+            },
+
+            // LIGHTNING
+            Self::Jolt => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MinorAction,
+                target_number: 10,
+                test: Some(Focus::ConstitutionStamina),
+            },
+            Self::LightningLash => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: Some(Focus::ConstitutionStamina),
+            },
+            Self::ChainLightning => SpellProperties {
+                mp_cost: 7,
+                casting_time: CastingTime::MajorAction,
+                target_number: 14,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+            Self::Thunderstorm => SpellProperties {
+                mp_cost: 10,
+                casting_time: CastingTime::MajorAction,
+                target_number: 16,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+
+            // POWER
+            Self::ArcaneShield => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MinorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::ForcePush => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: Some(Focus::StrengthMight),
+            },
+            Self::ArcaneCage => SpellProperties {
+                mp_cost: 6,
+                casting_time: CastingTime::MajorAction,
+                target_number: 13,
+                test: Some(Focus::WillpowerSelfDiscipline),
+            },
+            Self::Disintegrate => SpellProperties {
+                mp_cost: 10,
+                casting_time: CastingTime::MajorAction,
+                target_number: 16,
+                test: Some(Focus::ConstitutionStamina),
+            },
+
+            // SHADOW
+            Self::ShadowCloak => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MajorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::Dread => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: Some(Focus::WillpowerCourage),
+            },
+            Self::ShadowWalk => SpellProperties {
+                mp_cost: 6,
+                casting_time: CastingTime::MajorAction,
+                target_number: 13,
+                test: None,
+            },
+            Self::Nightfall => SpellProperties {
+                mp_cost: 9,
+                casting_time: CastingTime::MajorAction,
+                target_number: 15,
+                test: Some(Focus::WillpowerCourage),
+            },
+
+            // WATER
+            Self::WaterBreathing => SpellProperties {
+                mp_cost: 2,
+                casting_time: CastingTime::MajorAction,
+                target_number: 10,
+                test: None,
+            },
+            Self::WaterWhip => SpellProperties {
+                mp_cost: 3,
+                casting_time: CastingTime::MajorAction,
+                target_number: 11,
+                test: Some(Focus::DexterityAcrobatics),
+            },
+            Self::Drown => SpellProperties {
+                mp_cost: 7,
+                casting_time: CastingTime::MajorAction,
+                target_number: 14,
+                test: Some(Focus::ConstitutionStamina),
+            },
+            Self::TidalWave => SpellProperties {
+                mp_cost: 10,
+                casting_time: CastingTime::MajorAction,
+                target_number: 16,
+                test: Some(Focus::StrengthMight),
+            },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            // AIR
+            Self::WindBlast => "Wind Blast",
+            Self::Updraft => "Updraft",
+            Self::Whirlwind => "Whirlwind",
+            Self::Tempest => "Tempest",
+
+            // DIVINATION
+            Self::SenseMagic => "Sense Magic",
+            Self::Foresight => "Foresight",
+            Self::TrueSight => "True Sight",
+            Self::Prophecy => "Prophecy",
+
+            // EARTH
+            Self::Stoneskin => "Stoneskin",
+            Self::Tremor => "Tremor",
+            Self::EarthenGrasp => "Earthen Grasp",
+            Self::Earthquake => "Earthquake",
+
+            // FIRE
+            Self::FlameBlast => "Flame Blast",
+            Self::FireShield => "Fire Shield",
+            Self::Fireball => "Fireball",
+            Self::Inferno => "Inferno",
+
+            // HEALING
+            Self::HealingTouch => "Healing Touch",
+            Self::Purify => "Purify",
+            Self::MassHealing => "Mass Healing",
+            Self::Restoration => "Restoration",
+
+            // HEROIC
+            Self::Bolster => "Bolster",
+            Self::HeroicInspiration => "Heroic Inspiration",
+            Self::ChampionsAura => "Champion's Aura",
+            Self::LegendaryValor => "Legendary Valor",
+
+            // LIGHTNING
+            Self::Jolt => "Jolt",
+            Self::LightningLash => "Lightning Lash",
+            Self::ChainLightning => "Chain Lightning",
+            Self::Thunderstorm => "Thunderstorm",
+
+            // POWER
+            Self::ArcaneShield => "Arcane Shield",
+            Self::ForcePush => "Force Push",
+            Self::ArcaneCage => "Arcane Cage",
+            Self::Disintegrate => "Disintegrate",
+
+            // SHADOW
+            Self::ShadowCloak => "Shadow Cloak",
+            Self::Dread => "Dread",
+            Self::ShadowWalk => "Shadow Walk",
+            Self::Nightfall => "Nightfall",
+
+            // WATER
+            Self::WaterBreathing => "Water Breathing",
+            Self::WaterWhip => "Water Whip",
+            Self::Drown => "Drown",
+            Self::TidalWave => "Tidal Wave",
+        }
+    }
+}
+
+impl std::fmt::Display for Spell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpellProperties {
+    /// How many magic points it costs to cast the spell.
+    pub mp_cost: u8,
+
+    /// How long it takes to cast the spell.
+    pub casting_time: CastingTime,
+
+    /// The target number for the casting test.
+    pub target_number: u8,
+
+    /// The focus targets use to resist the spell, if they get a test at all.
+    pub test: Option<Focus>,
+}
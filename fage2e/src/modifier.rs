@@ -3,7 +3,7 @@
 //!
 //! This is especially important for explaining to users where their numbers are coming from.
 
-use super::{Ability, Ancestry, Class, Focus, Dice, DiceWithMod, Power};
+use super::{Ability, Ancestry, Class, Equipment, Focus, Dice, DiceWithMod, Power, Stunt, Talent, WeaponGroup};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -16,9 +16,13 @@ pub enum ModifierSource {
     Ancestry(Ancestry),
     Class(Class),
     Core,
+    Equipment(Equipment),
     Focus(Focus),
     Level(u8),
     Power(Power),
+    Stunt(Stunt),
+    Talent(Talent),
+    Untrained(WeaponGroup),
 }
 
 impl std::fmt::Display for ModifierSource {
@@ -28,9 +32,13 @@ impl std::fmt::Display for ModifierSource {
             Self::Ancestry(ancestry) => ancestry.fmt(f),
             Self::Class(class) => class.fmt(f),
             Self::Core => write!(f, "Core"),
+            Self::Equipment(equipment) => equipment.fmt(f),
             Self::Focus(focus) => focus.fmt(f),
             Self::Level(level) => write!(f, "Level {}", level),
             Self::Power(power) => power.fmt(f),
+            Self::Stunt(stunt) => stunt.fmt(f),
+            Self::Talent(talent) => talent.fmt(f),
+            Self::Untrained(group) => write!(f, "Untrained ({})", group),
         }
     }
 }
@@ -57,6 +65,18 @@ pub struct AdditiveModifier {
     pub source: ModifierSource,
 }
 
+/// A modifier that only applies in situations the character sheet can't check for, such as
+/// what the target's abilities are.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConditionalModifier<T: std::fmt::Debug + Clone> {
+    /// The value to add when the conditions are met.
+    pub value: T,
+
+    /// The modifier's source, which describes the conditions.
+    pub source: ModifierSource,
+}
+
 /// A set of modifiers to a dice roll or simple value and their sources.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -68,7 +88,12 @@ pub struct ModifierSet<T: std::fmt::Debug + Clone> {
     /// A list of additive modifiers on top of the base value/roll.
     pub additive: Vec<AdditiveModifier>,
 
-    // TODO: Optional divider on final result?
+    /// If specified, the final result is halved (rounded down), and this is why.
+    pub halved: Option<ModifierSource>,
+
+    /// Modifiers that only apply sometimes. These aren't part of the final value; the user
+    /// adds them in when their conditions are met.
+    pub conditional: Vec<ConditionalModifier<T>>,
 }
 
 /// A value with modifiers.
@@ -92,6 +117,9 @@ impl Value {
         for additive in &self.modifiers.additive {
             val += additive.value as i16;
         }
+        if self.modifiers.halved.is_some() {
+            val = val.div_euclid(2);
+        }
         val
     }
 }
@@ -125,6 +153,9 @@ impl DiceRoll {
         for additive in &self.modifiers.additive {
             val += additive.value as i16;
         }
+        if self.modifiers.halved.is_some() {
+            val = val.div_euclid(2);
+        }
         val
     }
 }
@@ -3,7 +3,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::draak;
+use crate::{envoy, mage, rogue, warrior, draak, dwarf, gnome, goblin, halfling, human, orc, wildfolk, Character, DiceWithMod, Focus, Specialization, SpecializationDetails, Stunt};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -13,24 +13,48 @@ pub enum Power {
 
     // Talents and Specializations
     // Talent(),  // TODO
-    // Specialization(), // TODO
+    Specialization(Specialization),
 
     // Class-based powers
-    // Envoy(),    // TODO
-    // Mage(),     // TODO
-    // Rogue(),    // TODO
-    // Warrior(),  // TODO
+    Envoy(envoy::EnvoyPower),
+    Mage(mage::MagePower),
+    Rogue(rogue::RoguePower),
+    Warrior(warrior::WarriorPower),
 
     // Ancestry-based powers
     Draak(draak::DraakPower),
-    // Dwarf(),     // TODO: Stout
+    Dwarf(dwarf::DwarfPower),
     // Elf ancestry has no special powers.
-    // Gnome(),     // TODO: Animal Speech
-    // Goblin(),    // TODO: Swift
-    // Halfling(),  // TODO: Steady
-    // Human(),     // TODO: Adaptable Focus
-    // Orc(),       // TODO: Tough
-    // Wildfolk(),  // TODO: Glide, Natural Weapon
+    Gnome(gnome::GnomePower),
+    Goblin(goblin::GoblinPower),
+    Halfling(halfling::HalflingPower),
+    Human(human::HumanPower),
+    Orc(orc::OrcPower),
+    Wildfolk(wildfolk::WildfolkPower),
+}
+
+impl From<envoy::EnvoyPower> for Power {
+    fn from(value: envoy::EnvoyPower) -> Self {
+        Power::Envoy(value)
+    }
+}
+
+impl From<mage::MagePower> for Power {
+    fn from(value: mage::MagePower) -> Self {
+        Power::Mage(value)
+    }
+}
+
+impl From<rogue::RoguePower> for Power {
+    fn from(value: rogue::RoguePower) -> Self {
+        Power::Rogue(value)
+    }
+}
+
+impl From<warrior::WarriorPower> for Power {
+    fn from(value: warrior::WarriorPower) -> Self {
+        Power::Warrior(value)
+    }
 }
 
 impl From<draak::DraakPower> for Power {
@@ -39,17 +63,69 @@ impl From<draak::DraakPower> for Power {
     }
 }
 
+impl From<dwarf::DwarfPower> for Power {
+    fn from(value: dwarf::DwarfPower) -> Self {
+        Power::Dwarf(value)
+    }
+}
+
+impl From<gnome::GnomePower> for Power {
+    fn from(value: gnome::GnomePower) -> Self {
+        Power::Gnome(value)
+    }
+}
+
+impl From<goblin::GoblinPower> for Power {
+    fn from(value: goblin::GoblinPower) -> Self {
+        Power::Goblin(value)
+    }
+}
+
+impl From<halfling::HalflingPower> for Power {
+    fn from(value: halfling::HalflingPower) -> Self {
+        Power::Halfling(value)
+    }
+}
+
+impl From<human::HumanPower> for Power {
+    fn from(value: human::HumanPower) -> Self {
+        Power::Human(value)
+    }
+}
+
+impl From<orc::OrcPower> for Power {
+    fn from(value: orc::OrcPower) -> Self {
+        Power::Orc(value)
+    }
+}
+
+impl From<wildfolk::WildfolkPower> for Power {
+    fn from(value: wildfolk::WildfolkPower) -> Self {
+        Power::Wildfolk(value)
+    }
+}
+
 impl std::fmt::Display for Power {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::DarkSight => write!(f, "Dark Sight"),
+            Self::Specialization(specialization) => specialization.fmt(f),
+            Self::Envoy(power) => power.fmt(f),
+            Self::Mage(power) => power.fmt(f),
+            Self::Rogue(power) => power.fmt(f),
+            Self::Warrior(power) => power.fmt(f),
             Self::Draak(power) => power.fmt(f),
+            Self::Dwarf(power) => power.fmt(f),
+            Self::Gnome(power) => power.fmt(f),
+            Self::Goblin(power) => power.fmt(f),
+            Self::Halfling(power) => power.fmt(f),
+            Self::Human(power) => power.fmt(f),
+            Self::Orc(power) => power.fmt(f),
+            Self::Wildfolk(power) => power.fmt(f),
         }
     }
 }
 
-// TODO: From implementations for EnvoyPower, MagePower, etc.
-
 /// The mechanics for a given power.
 pub trait PowerMechanics {
     /// What power this object references.
@@ -66,6 +142,40 @@ pub trait PowerMechanics {
         None
     }
 
+    /// The focus this power lets the character use to resist spells, if any.
+    fn magical_resistance_focus(&self) -> Option<Focus> {
+        None
+    }
+
+    /// The bonus this power adds to the character's speed, if any.
+    fn speed_bonus(&self) -> Option<i8> {
+        None
+    }
+
+    /// The bonus this power adds to the character's maximum health, if any.
+    fn health_bonus(&self) -> Option<i8> {
+        None
+    }
+
+    /// How much of the character's armor penalty this power cancels out, if any.
+    fn armor_penalty_reduction(&self) -> Option<i8> {
+        None
+    }
+
+    /// Extra damage this power adds to attacks under conditions the sheet can't check,
+    /// such as what the target's abilities are.
+    ///
+    /// This is attached to damage rolls as a conditional modifier. Takes the character
+    /// since some powers only work in certain armor.
+    fn conditional_damage(&self, _char: &Character) -> Option<DiceWithMod> {
+        None
+    }
+
+    /// How many fewer SP the given stunt costs because of this power, if any.
+    fn stunt_cost_reduction(&self, _stunt: Stunt) -> Option<u8> {
+        None
+    }
+
     // TODO
 }
 
@@ -85,7 +195,22 @@ pub fn into_generic_power_option<T: PowerMechanics>(value: &Option<T>) -> Option
 pub struct PowerDetails {
     pub dark_sight: Option<DarkSightDetails>,
 
+    /// The specializations the character has taken, in the order they were taken.
+    pub specializations: Vec<SpecializationDetails>,
+
+    pub envoy: envoy::EnvoyPowerDetails,
+    pub mage: mage::MagePowerDetails,
+    pub rogue: rogue::RoguePowerDetails,
+    pub warrior: warrior::WarriorPowerDetails,
+
     pub draak: draak::DraakPowerDetails,
+    pub dwarf: dwarf::DwarfPowerDetails,
+    pub gnome: gnome::GnomePowerDetails,
+    pub goblin: goblin::GoblinPowerDetails,
+    pub halfling: halfling::HalflingPowerDetails,
+    pub human: human::HumanPowerDetails,
+    pub orc: orc::OrcPowerDetails,
+    pub wildfolk: wildfolk::WildfolkPowerDetails,
 }
 
 impl PowerDetails {
@@ -95,8 +220,20 @@ impl PowerDetails {
             into_generic_power_option(&self.dark_sight)
         ]
             .into_iter()
-            .filter_map(|opt| opt)
+            .flatten()
+            .chain(self.specializations.iter().map(|s| s as &dyn PowerMechanics))
+            .chain(self.envoy.iter())
+            .chain(self.mage.iter())
+            .chain(self.rogue.iter())
+            .chain(self.warrior.iter())
             .chain(self.draak.iter())
+            .chain(self.dwarf.iter())
+            .chain(self.gnome.iter())
+            .chain(self.goblin.iter())
+            .chain(self.halfling.iter())
+            .chain(self.human.iter())
+            .chain(self.orc.iter())
+            .chain(self.wildfolk.iter())
     }
 }
 
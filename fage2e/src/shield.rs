@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+//! Shields
+
+use strum::EnumIter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Shield {
+    LightShield,
+    MediumShield,
+    HeavyShield,
+}
+
+impl Shield {
+    pub fn properties(&self) -> ShieldProperties {
+        match self {
+            Self::LightShield => ShieldProperties {
+                defense_bonus: 1,
+                min_strength: None,
+            },
+            Self::MediumShield => ShieldProperties {
+                defense_bonus: 2,
+                min_strength: Some(1),
+            },
+            Self::HeavyShield => ShieldProperties {
+                defense_bonus: 3,
+                min_strength: Some(3),
+            },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::LightShield => "Light Shield",
+            Self::MediumShield => "Medium Shield",
+            Self::HeavyShield => "Heavy Shield",
+        }
+    }
+}
+
+impl std::fmt::Display for Shield {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShieldProperties {
+    /// How much the shield adds to the wielder's defense.
+    pub defense_bonus: i8,
+
+    /// The strength needed to wield the shield without penalty.
+    pub min_strength: Option<i8>,
+    // TODO: cost
+}
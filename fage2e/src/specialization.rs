@@ -0,0 +1,130 @@
+#![allow(dead_code)]
+//! Class specializations.
+
+use strum::IntoEnumIterator;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{envoy, mage, rogue, warrior};
+use crate::{Class, Power, PowerMechanics, TalentDegree, TalentRequirement};
+
+/// A specialization from any class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Specialization {
+    Envoy(envoy::EnvoySpecialization),
+    Mage(mage::MageSpecialization),
+    Rogue(rogue::RogueSpecialization),
+    Warrior(warrior::WarriorSpecialization),
+}
+
+impl Specialization {
+    /// All specializations available to the given class.
+    pub fn for_class(class: Class) -> Vec<Self> {
+        match class {
+            Class::Envoy => envoy::EnvoySpecialization::iter().map(Self::from).collect(),
+            Class::Mage => mage::MageSpecialization::iter().map(Self::from).collect(),
+            Class::Rogue => rogue::RogueSpecialization::iter().map(Self::from).collect(),
+            Class::Warrior => warrior::WarriorSpecialization::iter().map(Self::from).collect(),
+        }
+    }
+
+    /// The class this specialization belongs to.
+    pub fn class(&self) -> Class {
+        match self {
+            Self::Envoy(_) => Class::Envoy,
+            Self::Mage(_) => Class::Mage,
+            Self::Rogue(_) => Class::Rogue,
+            Self::Warrior(_) => Class::Warrior,
+        }
+    }
+
+    /// The display name for this specialization.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Envoy(s) => s.name(),
+            Self::Mage(s) => s.name(),
+            Self::Rogue(s) => s.name(),
+            Self::Warrior(s) => s.name(),
+        }
+    }
+
+    /// What a character needs before they can take this specialization.
+    pub fn requirements(&self) -> &'static [TalentRequirement] {
+        match self {
+            Self::Envoy(s) => s.requirements(),
+            Self::Mage(s) => s.requirements(),
+            Self::Rogue(s) => s.requirements(),
+            Self::Warrior(s) => s.requirements(),
+        }
+    }
+
+    /// The benefit granted at the given degree of this specialization.
+    pub fn benefit(&self, degree: TalentDegree) -> &'static str {
+        match self {
+            Self::Envoy(s) => s.benefit(degree),
+            Self::Mage(s) => s.benefit(degree),
+            Self::Rogue(s) => s.benefit(degree),
+            Self::Warrior(s) => s.benefit(degree),
+        }
+    }
+}
+
+impl From<envoy::EnvoySpecialization> for Specialization {
+    fn from(value: envoy::EnvoySpecialization) -> Self {
+        Specialization::Envoy(value)
+    }
+}
+
+impl From<mage::MageSpecialization> for Specialization {
+    fn from(value: mage::MageSpecialization) -> Self {
+        Specialization::Mage(value)
+    }
+}
+
+impl From<rogue::RogueSpecialization> for Specialization {
+    fn from(value: rogue::RogueSpecialization) -> Self {
+        Specialization::Rogue(value)
+    }
+}
+
+impl From<warrior::WarriorSpecialization> for Specialization {
+    fn from(value: warrior::WarriorSpecialization) -> Self {
+        Specialization::Warrior(value)
+    }
+}
+
+impl std::fmt::Display for Specialization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Metadata about a specialization the character has taken.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpecializationDetails {
+    pub specialization: Specialization,
+
+    /// How far the character has advanced in this specialization.
+    pub degree: TalentDegree,
+}
+
+impl PowerMechanics for SpecializationDetails {
+    fn power(&self) -> Power {
+        Power::Specialization(self.specialization)
+    }
+
+    fn name(&self) -> String {
+        format!("{} ({})", self.specialization, self.degree)
+    }
+
+    fn description(&self) -> String {
+        TalentDegree::iter()
+            .filter(|degree| *degree <= self.degree)
+            .map(|degree| format!("{}: {}", degree, self.specialization.benefit(degree)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,329 @@
+#![allow(dead_code)]
+//! Stunts and the stunts a character has learned.
+
+use strum::EnumIter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::talent::ALL_CLASSES;
+use crate::{Ability, Ancestry, Class, Dice, DiceWithMod};
+
+/// The kinds of situations a stunt can be performed in.
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StuntType {
+    Combat,
+    Exploration,
+    Roleplaying,
+    Spell,
+}
+
+impl std::fmt::Display for StuntType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Combat => write!(f, "Combat"),
+            Self::Exploration => write!(f, "Exploration"),
+            Self::Roleplaying => write!(f, "Roleplaying"),
+            Self::Spell => write!(f, "Spell"),
+        }
+    }
+}
+
+/// How a character came to know a stunt.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StuntSource {
+    /// Learned as an in-class stunt advancement.
+    Class,
+
+    /// Learned as an out-of-class stunt advancement.
+    OutOfClass,
+
+    /// Granted by the character's ancestry.
+    Ancestry(Ancestry),
+}
+
+impl std::fmt::Display for StuntSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Class => write!(f, "Class"),
+            Self::OutOfClass => write!(f, "Out-of-Class"),
+            Self::Ancestry(ancestry) => write!(f, "{} Ancestry", ancestry),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Stunt {
+    // Combat stunts
+    DefensiveStance,
+    Disarm,
+    DualStrike,
+    KnockProne,
+    LethalBlow,
+    LightningAttack,
+    MightyBlow,
+    PierceArmor,
+    RapidReload,
+    SeizeTheInitiative,
+    Skirmish,
+    Taunt,
+    Threaten,
+
+    // Exploration stunts
+    AdvantageousPositioning,
+    EfficientWork,
+    SpeedDemon,
+    TheUpperHand,
+
+    // Roleplaying stunts
+    BonMot,
+    Enrage,
+    Flirt,
+    SwayTheCrowd,
+    TowerOfWill,
+
+    // Spell stunts
+    FastCasting,
+    LastingSpell,
+    MightySpell,
+    SkillfulCasting,
+    SplitSpell,
+
+    // Ancestry stunts
+    FlameBreath,
+}
+
+impl Stunt {
+    /// The display name for this stunt.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DefensiveStance => "Defensive Stance",
+            Self::Disarm => "Disarm",
+            Self::DualStrike => "Dual Strike",
+            Self::KnockProne => "Knock Prone",
+            Self::LethalBlow => "Lethal Blow",
+            Self::LightningAttack => "Lightning Attack",
+            Self::MightyBlow => "Mighty Blow",
+            Self::PierceArmor => "Pierce Armor",
+            Self::RapidReload => "Rapid Reload",
+            Self::SeizeTheInitiative => "Seize the Initiative",
+            Self::Skirmish => "Skirmish",
+            Self::Taunt => "Taunt",
+            Self::Threaten => "Threaten",
+            Self::AdvantageousPositioning => "Advantageous Positioning",
+            Self::EfficientWork => "Efficient Work",
+            Self::SpeedDemon => "Speed Demon",
+            Self::TheUpperHand => "The Upper Hand",
+            Self::BonMot => "Bon Mot",
+            Self::Enrage => "Enrage",
+            Self::Flirt => "Flirt",
+            Self::SwayTheCrowd => "Sway the Crowd",
+            Self::TowerOfWill => "Tower of Will",
+            Self::FastCasting => "Fast Casting",
+            Self::LastingSpell => "Lasting Spell",
+            Self::MightySpell => "Mighty Spell",
+            Self::SkillfulCasting => "Skillful Casting",
+            Self::SplitSpell => "Split Spell",
+            Self::FlameBreath => "Flame Breath",
+        }
+    }
+
+    /// A short description of what this stunt does.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::DefensiveStance => "Gain +2 Defense until the beginning of your next turn.",
+            Self::Disarm => "Knock your opponent's weapon out of their hand.",
+            Self::DualStrike => "Your attack also hits a second adjacent enemy.",
+            Self::KnockProne => "Knock your opponent to the ground.",
+            Self::LethalBlow => "Inflict an extra 2d6 damage on the attack.",
+            Self::LightningAttack => "Make a second attack against the same or a different enemy.",
+            Self::MightyBlow => "Inflict an extra 1d6 damage on the attack.",
+            Self::PierceArmor => "Your opponent's armor rating is halved against this attack.",
+            Self::RapidReload => "Immediately reload a missile weapon.",
+            Self::SeizeTheInitiative => "Move to the top of the initiative order.",
+            Self::Skirmish => "Move yourself or your target 2 yards in any direction.",
+            Self::Taunt => "Insult or distract your opponent, penalizing their next action.",
+            Self::Threaten => "Strike a threatening pose, challenging an opponent.",
+            Self::AdvantageousPositioning => "Gain +1 on your next test in the same situation.",
+            Self::EfficientWork => "Complete the task in half the usual time.",
+            Self::SpeedDemon => "Complete an extended test in a single step.",
+            Self::TheUpperHand => "Gain +1 on your next test against the same opponent or obstacle.",
+            Self::BonMot => "Deliver a witty remark that leaves a lasting impression.",
+            Self::Enrage => "Provoke a character into a rash action.",
+            Self::Flirt => "Draw the romantic interest of a character in the scene.",
+            Self::SwayTheCrowd => "Your persuasion affects additional listeners.",
+            Self::TowerOfWill => "Gain +1 to resist opposed social tests for the rest of the encounter.",
+            Self::FastCasting => "Cast another spell with a casting time of a major action as a minor action.",
+            Self::LastingSpell => "The spell's duration is doubled.",
+            Self::MightySpell => "Inflict an extra 1d6 damage with the spell.",
+            Self::SkillfulCasting => "Reduce the spell's MP cost by 1.",
+            Self::SplitSpell => "The spell affects an additional target.",
+            Self::FlameBreath => "Breathe a gout of fire at your enemies, damaging everyone in the area.",
+        }
+    }
+
+    /// What kind of stunt this is.
+    pub fn stunt_type(&self) -> StuntType {
+        match self {
+            Self::DefensiveStance
+            | Self::Disarm
+            | Self::DualStrike
+            | Self::KnockProne
+            | Self::LethalBlow
+            | Self::LightningAttack
+            | Self::MightyBlow
+            | Self::PierceArmor
+            | Self::RapidReload
+            | Self::SeizeTheInitiative
+            | Self::Skirmish
+            | Self::Taunt
+            | Self::Threaten
+            | Self::FlameBreath => StuntType::Combat,
+            Self::AdvantageousPositioning
+            | Self::EfficientWork
+            | Self::SpeedDemon
+            | Self::TheUpperHand => StuntType::Exploration,
+            Self::BonMot
+            | Self::Enrage
+            | Self::Flirt
+            | Self::SwayTheCrowd
+            | Self::TowerOfWill => StuntType::Roleplaying,
+            Self::FastCasting
+            | Self::LastingSpell
+            | Self::MightySpell
+            | Self::SkillfulCasting
+            | Self::SplitSpell => StuntType::Spell,
+        }
+    }
+
+    /// How many stunt points it costs to perform this stunt.
+    pub fn sp_cost(&self) -> u8 {
+        match self {
+            Self::DefensiveStance => 2,
+            Self::Disarm => 2,
+            Self::DualStrike => 4,
+            Self::KnockProne => 2,
+            Self::LethalBlow => 5,
+            Self::LightningAttack => 3,
+            Self::MightyBlow => 2,
+            Self::PierceArmor => 2,
+            Self::RapidReload => 1,
+            Self::SeizeTheInitiative => 4,
+            Self::Skirmish => 1,
+            Self::Taunt => 2,
+            Self::Threaten => 2,
+            Self::AdvantageousPositioning => 1,
+            Self::EfficientWork => 3,
+            Self::SpeedDemon => 4,
+            Self::TheUpperHand => 2,
+            Self::BonMot => 2,
+            Self::Enrage => 3,
+            Self::Flirt => 1,
+            Self::SwayTheCrowd => 2,
+            Self::TowerOfWill => 1,
+            Self::FastCasting => 3,
+            Self::LastingSpell => 2,
+            Self::MightySpell => 2,
+            Self::SkillfulCasting => 1,
+            Self::SplitSpell => 2,
+            Self::FlameBreath => 3,
+        }
+    }
+
+    /// The classes for which this counts as an in-class stunt.
+    ///
+    /// Ancestry stunts aren't available to any class.
+    pub fn classes(&self) -> &'static [Class] {
+        match self {
+            Self::DefensiveStance => &ALL_CLASSES,
+            Self::Disarm => &[Class::Envoy, Class::Rogue, Class::Warrior],
+            Self::DualStrike => &[Class::Warrior],
+            Self::KnockProne => &[Class::Rogue, Class::Warrior],
+            Self::LethalBlow => &[Class::Rogue, Class::Warrior],
+            Self::LightningAttack => &[Class::Rogue, Class::Warrior],
+            Self::MightyBlow => &[Class::Envoy, Class::Rogue, Class::Warrior],
+            Self::PierceArmor => &[Class::Rogue, Class::Warrior],
+            Self::RapidReload => &[Class::Rogue, Class::Warrior],
+            Self::SeizeTheInitiative => &ALL_CLASSES,
+            Self::Skirmish => &ALL_CLASSES,
+            Self::Taunt => &[Class::Envoy, Class::Rogue],
+            Self::Threaten => &[Class::Envoy, Class::Warrior],
+            Self::AdvantageousPositioning => &ALL_CLASSES,
+            Self::EfficientWork => &[Class::Mage, Class::Rogue],
+            Self::SpeedDemon => &[Class::Mage, Class::Rogue],
+            Self::TheUpperHand => &ALL_CLASSES,
+            Self::BonMot => &[Class::Envoy, Class::Rogue],
+            Self::Enrage => &[Class::Envoy, Class::Rogue],
+            Self::Flirt => &[Class::Envoy, Class::Rogue],
+            Self::SwayTheCrowd => &[Class::Envoy],
+            Self::TowerOfWill => &[Class::Envoy, Class::Mage],
+            Self::FastCasting => &[Class::Mage],
+            Self::LastingSpell => &[Class::Mage],
+            Self::MightySpell => &[Class::Mage],
+            Self::SkillfulCasting => &[Class::Mage],
+            Self::SplitSpell => &[Class::Mage],
+            Self::FlameBreath => &[],
+        }
+    }
+
+    /// The ancestry that grants this stunt, if it's an ancestry stunt.
+    ///
+    /// Ancestry stunts can't be learned through stunt advancements.
+    pub fn ancestry(&self) -> Option<Ancestry> {
+        match self {
+            Self::FlameBreath => Some(Ancestry::Draak),
+            _ => None,
+        }
+    }
+
+    /// The damage this stunt deals on its own, if any, before ability modifiers.
+    ///
+    /// Stunts that add to an attack's damage, such as Mighty Blow, aren't included.
+    pub fn damage(&self) -> Option<DiceWithMod> {
+        match self {
+            Self::FlameBreath => Some(DiceWithMod { dice: Dice::d6(2), modifier: 0 }),
+            _ => None,
+        }
+    }
+
+    /// The ability added to this stunt's damage, if it deals damage.
+    pub fn damage_ability(&self) -> Option<Ability> {
+        match self {
+            Self::FlameBreath => Some(Ability::Constitution),
+            _ => None,
+        }
+    }
+
+    /// The area this stunt affects, if it affects an area.
+    pub fn area(&self) -> Option<StuntArea> {
+        match self {
+            Self::FlameBreath => Some(StuntArea::Cone { length_yards: 6 }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Stunt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The shape and size of the area a stunt affects.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StuntArea {
+    /// A cone starting at the character.
+    Cone { length_yards: u8 },
+}
+
+impl std::fmt::Display for StuntArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cone { length_yards } => write!(f, "{}-yard cone", length_yards),
+        }
+    }
+}
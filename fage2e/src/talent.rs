@@ -0,0 +1,273 @@
+#![allow(dead_code)]
+//! Talents and their degrees.
+
+use strum::EnumIter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Ability, Character, Class, Focus, WeaponGroup};
+
+pub(crate) static ALL_CLASSES: [Class; 4] = [Class::Envoy, Class::Mage, Class::Rogue, Class::Warrior];
+
+/// How far a character has advanced in a talent.
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TalentDegree {
+    Novice,
+    Expert,
+    Master,
+}
+
+impl TalentDegree {
+    /// The display name for this degree.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Novice => "Novice",
+            Self::Expert => "Expert",
+            Self::Master => "Master",
+        }
+    }
+
+    /// The degree after this one, if any.
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            Self::Novice => Some(Self::Expert),
+            Self::Expert => Some(Self::Master),
+            Self::Master => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TalentDegree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Something a character must have before they can take a talent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TalentRequirement {
+    /// The ability must be at least the given score.
+    Ability(Ability, i8),
+
+    /// The character must have the focus.
+    Focus(Focus),
+
+    /// The character must be trained in the weapon group.
+    WeaponGroup(WeaponGroup),
+}
+
+impl TalentRequirement {
+    /// Whether the given character meets this requirement.
+    pub fn is_met(&self, char: &Character) -> bool {
+        match self {
+            Self::Ability(ability, score) => char.mechanical_properties.abilities.get(*ability).score >= *score,
+            Self::Focus(focus) => char.mechanical_properties.focuses.contains_key(focus),
+            Self::WeaponGroup(group) => char.mechanical_properties.weapon_training.contains(group),
+        }
+    }
+}
+
+impl std::fmt::Display for TalentRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ability(ability, score) => write!(f, "{} {}", ability, score),
+            Self::Focus(focus) => focus.fmt(f),
+            Self::WeaponGroup(group) => write!(f, "{} weapon group", group),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Talent {
+    Alchemy,
+    AnimalTraining,
+    ArcaneTraining,
+    ArcheryStyle,
+    ArmorTraining,
+    Carousing,
+    Chirurgy,
+    Command,
+    Contacts,
+    DualWeaponStyle,
+    Horsemanship,
+    Intrigue,
+    Linguistics,
+    Lore,
+    Music,
+    Observation,
+    Oratory,
+    QuickReflexes,
+    Scouting,
+    SingleWeaponStyle,
+    Thievery,
+    ThrownWeaponStyle,
+    TwoHanderStyle,
+    UnarmedStyle,
+    WeaponAndShieldStyle,
+}
+
+impl Talent {
+    /// The display name for this talent.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Alchemy => "Alchemy",
+            Self::AnimalTraining => "Animal Training",
+            Self::ArcaneTraining => "Arcane Training",
+            Self::ArcheryStyle => "Archery Style",
+            Self::ArmorTraining => "Armor Training",
+            Self::Carousing => "Carousing",
+            Self::Chirurgy => "Chirurgy",
+            Self::Command => "Command",
+            Self::Contacts => "Contacts",
+            Self::DualWeaponStyle => "Dual Weapon Style",
+            Self::Horsemanship => "Horsemanship",
+            Self::Intrigue => "Intrigue",
+            Self::Linguistics => "Linguistics",
+            Self::Lore => "Lore",
+            Self::Music => "Music",
+            Self::Observation => "Observation",
+            Self::Oratory => "Oratory",
+            Self::QuickReflexes => "Quick Reflexes",
+            Self::Scouting => "Scouting",
+            Self::SingleWeaponStyle => "Single Weapon Style",
+            Self::Thievery => "Thievery",
+            Self::ThrownWeaponStyle => "Thrown Weapon Style",
+            Self::TwoHanderStyle => "Two-Hander Style",
+            Self::UnarmedStyle => "Unarmed Style",
+            Self::WeaponAndShieldStyle => "Weapon and Shield Style",
+        }
+    }
+
+    /// A short description of what this talent does.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Alchemy => "You can brew potions, powders, and other alchemical concoctions.",
+            Self::AnimalTraining => "You can train animals to obey commands and perform tricks.",
+            Self::ArcaneTraining => "Your study of magic makes your spells harder to resist and, with practice, easier to cast.",
+            Self::ArcheryStyle => "You are skilled at fighting with bows.",
+            Self::ArmorTraining => "You have learned to fight in heavier armor with less hindrance.",
+            Self::Carousing => "You know how to have a good time and make friends doing it.",
+            Self::Chirurgy => "You are skilled at treating wounds and illnesses.",
+            Self::Command => "You can inspire and direct allies in the heat of battle.",
+            Self::Contacts => "You know people wherever you go and can call on them for favors.",
+            Self::DualWeaponStyle => "You are skilled at fighting with a weapon in each hand.",
+            Self::Horsemanship => "You are at home in the saddle, even in combat.",
+            Self::Intrigue => "You navigate plots, secrets, and courtly maneuvering with ease.",
+            Self::Linguistics => "You pick up new languages quickly.",
+            Self::Lore => "You have a deep store of knowledge to draw on.",
+            Self::Music => "You can move an audience with your playing and singing.",
+            Self::Observation => "Little escapes your notice.",
+            Self::Oratory => "You can sway crowds with your words.",
+            Self::QuickReflexes => "You react to danger faster than most.",
+            Self::Scouting => "You are adept at moving unseen and scouting ahead.",
+            Self::SingleWeaponStyle => "You are skilled at fighting with a single one-handed weapon and nothing in your other hand.",
+            Self::Thievery => "You are skilled at picking pockets and locks.",
+            Self::ThrownWeaponStyle => "You are skilled at fighting with thrown weapons.",
+            Self::TwoHanderStyle => "You are skilled at fighting with two-handed weapons.",
+            Self::UnarmedStyle => "You are skilled at fighting with your bare hands.",
+            Self::WeaponAndShieldStyle => "You are skilled at fighting with a weapon and shield.",
+        }
+    }
+
+    /// The classes that can take this talent.
+    pub fn classes(&self) -> &'static [Class] {
+        match self {
+            Self::Alchemy => &ALL_CLASSES,
+            Self::AnimalTraining => &ALL_CLASSES,
+            Self::ArcaneTraining => &[Class::Mage],
+            Self::ArcheryStyle => &[Class::Rogue, Class::Warrior],
+            Self::ArmorTraining => &[Class::Envoy, Class::Warrior],
+            Self::Carousing => &ALL_CLASSES,
+            Self::Chirurgy => &ALL_CLASSES,
+            Self::Command => &[Class::Envoy, Class::Warrior],
+            Self::Contacts => &[Class::Envoy, Class::Rogue],
+            Self::DualWeaponStyle => &[Class::Rogue, Class::Warrior],
+            Self::Horsemanship => &ALL_CLASSES,
+            Self::Intrigue => &[Class::Envoy, Class::Rogue],
+            Self::Linguistics => &ALL_CLASSES,
+            Self::Lore => &ALL_CLASSES,
+            Self::Music => &ALL_CLASSES,
+            Self::Observation => &ALL_CLASSES,
+            Self::Oratory => &[Class::Envoy],
+            Self::QuickReflexes => &[Class::Rogue, Class::Warrior],
+            Self::Scouting => &ALL_CLASSES,
+            Self::SingleWeaponStyle => &[Class::Envoy, Class::Rogue, Class::Warrior],
+            Self::Thievery => &[Class::Rogue],
+            Self::ThrownWeaponStyle => &[Class::Rogue, Class::Warrior],
+            Self::TwoHanderStyle => &[Class::Warrior],
+            Self::UnarmedStyle => &ALL_CLASSES,
+            Self::WeaponAndShieldStyle => &[Class::Envoy, Class::Warrior],
+        }
+    }
+
+    /// What a character needs before they can take this talent.
+    pub fn requirements(&self) -> &'static [TalentRequirement] {
+        match self {
+            Self::Alchemy => &[TalentRequirement::Focus(Focus::IntelligenceBrewing)],
+            Self::AnimalTraining => &[TalentRequirement::Focus(Focus::CommunicationAnimalHandling)],
+            Self::ArcaneTraining => &[TalentRequirement::Ability(Ability::Willpower, 1)],
+            Self::ArcheryStyle => &[TalentRequirement::WeaponGroup(WeaponGroup::Bows)],
+            Self::ArmorTraining => &[TalentRequirement::Ability(Ability::Strength, 1)],
+            Self::Carousing => &[TalentRequirement::Ability(Ability::Constitution, 1)],
+            Self::Chirurgy => &[TalentRequirement::Focus(Focus::IntelligenceHealing)],
+            Self::Command => &[TalentRequirement::Focus(Focus::CommunicationLeadership)],
+            Self::Contacts => &[TalentRequirement::Ability(Ability::Communication, 1)],
+            Self::DualWeaponStyle => &[TalentRequirement::Ability(Ability::Dexterity, 2)],
+            Self::Horsemanship => &[TalentRequirement::Focus(Focus::DexterityRiding)],
+            Self::Intrigue => &[TalentRequirement::Ability(Ability::Communication, 2)],
+            Self::Linguistics => &[TalentRequirement::Ability(Ability::Intelligence, 1)],
+            Self::Lore => &[TalentRequirement::Ability(Ability::Intelligence, 1)],
+            Self::Music => &[TalentRequirement::Focus(Focus::CommunicationPerformance)],
+            Self::Observation => &[TalentRequirement::Ability(Ability::Perception, 1)],
+            Self::Oratory => &[TalentRequirement::Focus(Focus::CommunicationPersuasion)],
+            Self::QuickReflexes => &[TalentRequirement::Ability(Ability::Dexterity, 2)],
+            Self::Scouting => &[TalentRequirement::Focus(Focus::DexterityStealth)],
+            Self::SingleWeaponStyle => &[TalentRequirement::Ability(Ability::Perception, 2)],
+            Self::Thievery => &[TalentRequirement::Ability(Ability::Dexterity, 1)],
+            Self::ThrownWeaponStyle => &[TalentRequirement::Ability(Ability::Accuracy, 2)],
+            Self::TwoHanderStyle => &[TalentRequirement::Ability(Ability::Strength, 3)],
+            Self::UnarmedStyle => &[TalentRequirement::WeaponGroup(WeaponGroup::Brawling)],
+            Self::WeaponAndShieldStyle => &[TalentRequirement::Ability(Ability::Strength, 2)],
+        }
+    }
+
+    /// The bonus this talent adds to spellpower at the given degree, if any.
+    pub fn spellpower_bonus(&self, degree: TalentDegree) -> Option<i8> {
+        match (self, degree) {
+            (Self::ArcaneTraining, TalentDegree::Novice) => Some(1),
+            (Self::ArcaneTraining, TalentDegree::Expert) => Some(2),
+            (Self::ArcaneTraining, TalentDegree::Master) => Some(3),
+            _ => None,
+        }
+    }
+
+    /// The bonus this talent adds to casting tests at the given degree, if any.
+    pub fn casting_test_bonus(&self, degree: TalentDegree) -> Option<i8> {
+        match (self, degree) {
+            (Self::ArcaneTraining, TalentDegree::Expert) => Some(1),
+            (Self::ArcaneTraining, TalentDegree::Master) => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Whether the given character is allowed to take (or advance) this talent.
+    ///
+    /// This checks class restrictions and requirements, but not the talent's current degree.
+    pub fn is_available_to(&self, char: &Character) -> bool {
+        let class_ok = match char.mechanical_properties.class {
+            None => false,
+            Some(class) => self.classes().contains(&class),
+        };
+        class_ok && self.requirements().iter().all(|req| req.is_met(char))
+    }
+}
+
+impl std::fmt::Display for Talent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
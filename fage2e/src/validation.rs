@@ -0,0 +1,194 @@
+#![allow(dead_code)]
+//! Whole-tree validation of advancements.
+
+use crate::{Advancement, AdvancementError, Character};
+
+/// What's wrong with a single advancement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationProblem {
+    /// The advancement hasn't been fully filled out yet.
+    Incomplete,
+
+    /// The advancement couldn't be applied.
+    Invalid(AdvancementError),
+}
+
+/// A single advancement that still needs the user's attention.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// Where the advancement is in the tree, e.g. `level1.ancestry.draak.benefits.selection2`.
+    ///
+    /// This is built from the names given by `Advancement::foreach`, starting from the root
+    /// name given to `validate`. The validated advancement itself has just the root name.
+    pub path: String,
+
+    /// What's wrong with the advancement.
+    pub problem: ValidationProblem,
+}
+
+/// Every incomplete or invalid advancement found in an advancement tree.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Whether every advancement was fully filled out and valid.
+    pub fn is_complete(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Whether every advancement was valid, even if some weren't filled out yet.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// The advancements that couldn't be applied.
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| matches!(issue.problem, ValidationProblem::Invalid(_)))
+    }
+
+    /// The advancements that haven't been fully filled out yet.
+    pub fn incomplete(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| issue.problem == ValidationProblem::Incomplete)
+    }
+
+    /// The report rolled up into the same form `Advancement::apply_all` returns.
+    ///
+    /// Returns:
+    /// * Ok(true) if there were no issues.
+    /// * Ok(false) if some advancements weren't filled out, but there were no errors.
+    /// * Err(e) with the first error, if there were any.
+    pub fn status(&self) -> Result<bool, AdvancementError> {
+        for issue in self.issues.iter() {
+            if let ValidationProblem::Invalid(err) = &issue.problem {
+                return Err(err.clone());
+            }
+        }
+        Ok(self.is_complete())
+    }
+}
+
+/// Apply the given advancement and all its sub-advancements to a copy of the given character,
+/// reporting every advancement that is incomplete or invalid.
+///
+/// Unlike `Advancement::apply_all`, this doesn't stop at the first error. Sub-advancements
+/// are still visited after their parent fails, so that the user can be shown all of their
+/// mistakes at once.
+///
+/// Args:
+/// * root: The name to start every issue's path with, e.g. `level1`. May be empty, in which
+///   case paths are relative to the given advancement.
+/// * adv: The advancement to validate.
+/// * char: The character to validate the advancement against.
+pub fn validate(root: &str, adv: &dyn Advancement, char: &Character) -> ValidationReport {
+    let mut char = char.clone();
+    let mut report = ValidationReport::default();
+    validate_recursive(adv, &mut char, root, &mut report);
+    report
+}
+
+fn validate_recursive(adv: &dyn Advancement, char: &mut Character, path: &str, report: &mut ValidationReport) {
+    let problem = match adv.apply_self(char) {
+        Ok(true) => None,
+        Ok(false) => Some(ValidationProblem::Incomplete),
+        Err(err) => Some(ValidationProblem::Invalid(err)),
+    };
+    if let Some(problem) = problem {
+        report.issues.push(ValidationIssue { path: path.to_owned(), problem });
+    }
+
+    adv.foreach(&mut |name, sub_adv| {
+        let sub_path = if path.is_empty() { name.to_owned() } else { format!("{}.{}", path, name) };
+        validate_recursive(sub_adv, char, &sub_path, report);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{draak, mage, Ability, AbilityDetermination, AncestryBenefitSelections, Arcana};
+    use crate::{Level1, Level1AncestrySelections, Level1ClassSelections, SelectAbilities};
+
+    fn paths(issues: impl Iterator<Item = ValidationIssue>) -> Vec<String> {
+        issues.map(|issue| issue.path).collect()
+    }
+
+    #[test]
+    fn empty_level1() {
+        let report = validate("level1", &Level1::default(), &Character::new());
+        assert_eq!(
+            paths(report.issues.iter().cloned()),
+            ["level1.name", "level1.abilities", "level1.class", "level1.ancestry", "level1.health"],
+        );
+        assert!(report.is_valid());
+        assert_eq!(report.status(), Ok(false));
+    }
+
+    #[test]
+    fn empty_root_gives_relative_paths() {
+        let report = validate("", &SelectAbilities::default(), &Character::new());
+        let expected: Vec<String> = (1..=13).map(|i| format!("advancement{}", i)).collect();
+        assert_eq!(paths(report.issues.iter().cloned()), expected);
+    }
+
+    #[test]
+    fn duplicate_ancestry_benefit() {
+        let level1 = Level1 {
+            ancestry: Level1AncestrySelections::Draak(draak::Level1Selections {
+                ability_focus: None,
+                benefits: AncestryBenefitSelections {
+                    selection1: Some(draak::DraakBenefit::ConstitutionStamina),
+                    selection2: Some(draak::DraakBenefit::ConstitutionStamina),
+                    selections_were_rolled: false,
+                },
+            }),
+            ..Default::default()
+        };
+        let report = validate("level1", &level1, &Character::new());
+        assert_eq!(
+            report.errors().cloned().collect::<Vec<_>>(),
+            [ValidationIssue {
+                path: "level1.ancestry.draak.benefits.selection2".to_owned(),
+                problem: ValidationProblem::Invalid(AdvancementError::DuplicateAncestryBenefit(
+                    draak::DraakBenefit::ConstitutionStamina.to_string(),
+                )),
+            }],
+        );
+        // Everything else is still reported.
+        assert!(report.incomplete().any(|issue| issue.path == "level1.ancestry.draak.ability_focus"));
+    }
+
+    #[test]
+    fn incomplete_slots() {
+        let level1 = Level1 {
+            abilities: AbilityDetermination::Select(SelectAbilities {
+                advancements: [None, Some(Ability::Accuracy), None, None, None, None, None, None, None, None, None, None, None],
+            }),
+            class: Level1ClassSelections::Mage(mage::Level1Selections {
+                arcana: mage::ArcanaSelection { choices: [Some(Arcana::Fire), None] },
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let incomplete = paths(validate("level1", &level1, &Character::new()).incomplete().cloned());
+        assert!(incomplete.contains(&"level1.abilities.select.advancement1".to_owned()));
+        assert!(!incomplete.contains(&"level1.abilities.select.advancement2".to_owned()));
+        assert!(!incomplete.contains(&"level1.class.mage.arcana.choice1".to_owned()));
+        assert!(incomplete.contains(&"level1.class.mage.arcana.choice2".to_owned()));
+    }
+
+    #[test]
+    fn ability_above_maximum() {
+        let mut advancements = [None; 13];
+        advancements[..4].fill(Some(Ability::Accuracy));
+        let report = validate("abilities", &SelectAbilities { advancements }, &Character::new());
+        assert_eq!(
+            report.errors().cloned().collect::<Vec<_>>(),
+            [ValidationIssue {
+                path: "abilities.advancement4".to_owned(),
+                problem: ValidationProblem::Invalid(AdvancementError::AbilityAboveMaximum { ability: Ability::Accuracy, max: 3 }),
+            }],
+        );
+    }
+}
@@ -119,7 +119,7 @@ pub enum Weapon {
     // DUELING
     MainGauche,
     Rapier,
-    SpikedBuckler, // TODO: Handle +1 defense vs melee-only?
+    SpikedBuckler,
 
     // HEAVY BLADES
     BastardSword,
@@ -619,6 +619,14 @@ impl Weapon {
             Self::Quarterstaff => "Quarterstaff",
         }
     }
+
+    /// The bonus to defense against melee attacks this weapon gives its wielder, if any.
+    pub fn melee_defense_bonus(&self) -> Option<i8> {
+        match self {
+            Self::SpikedBuckler => Some(1),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Weapon {
@@ -627,6 +635,23 @@ impl std::fmt::Display for Weapon {
     }
 }
 
+/// The kinds of attacks a character might have to defend against.
+#[derive(Debug, Copy, Clone, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AttackType {
+    Melee,
+    Ranged,
+}
+
+impl std::fmt::Display for AttackType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Melee => write!(f, "Melee"),
+            Self::Ranged => write!(f, "Ranged"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WeaponProperties {